@@ -181,7 +181,7 @@
 //! ```
 //!
 //! ```sql
-//! SELECT flight_number FROM departures LIMIT 25
+//! SELECT flight_number FROM departures ORDER BY id ASC LIMIT 25
 //! ```
 //!
 //! ```rust
@@ -197,11 +197,39 @@
 //! ```
 //!
 //! ```sql
-//! SELECT flight_number FROM departures WHERE id > 40 LIMIT 25
+//! SELECT flight_number FROM departures WHERE id > 40 ORDER BY id ASC LIMIT 25
 //! ```
+//!
+//! For pagination over multiple ordered columns, use a `Keyset` with a `Cursor`. The cursor encodes to an opaque token that can be handed to API clients and decoded again for the next request.
+//!
+//! ```rust
+//! use eloquent::{Cursor, Eloquent, Keyset, Value};
+//!
+//! let token = Cursor::next(vec![Value::Text("2024-01-01".to_string()), Value::Int(40)]).encode();
+//!
+//! let query = Eloquent::query()
+//!     .table("departures")
+//!     .select("flight_number")
+//!     .keyset(
+//!         Keyset::new(25)
+//!             .order_by_desc("departure_date")
+//!             .order_by_desc("id")
+//!             .cursor(Cursor::decode(&token)?),
+//!     )
+//!     .sql()?;
+//! ```
+//!
+//! ```sql
+//! SELECT flight_number FROM departures WHERE (departure_date < '2024-01-01' OR (departure_date = '2024-01-01' AND id < 40)) ORDER BY departure_date DESC, id DESC LIMIT 25
+//! ```
+//!
+//! Use `Cursor::previous()` to page backwards, the rows are then selected in reverse order.
 
 pub use eloquent_core::*;
 
+#[cfg(feature = "derive")]
+pub use eloquent_derive::Table;
+
 /// The main struct for building queries.
 pub struct Eloquent;
 
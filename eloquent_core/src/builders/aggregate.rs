@@ -0,0 +1,134 @@
+use crate::{
+    compilers::{conditions, group_by, havings, joins, selects, set_operations},
+    error::EloquentError,
+    Aggregate, Bindings, Function, QueryBuilder, Select,
+};
+
+pub struct AggregateBuilder;
+
+impl AggregateBuilder {
+    pub(crate) fn build(
+        builder: &QueryBuilder,
+        aggregate: &Aggregate,
+        sql: &mut String,
+        bindings: &mut Bindings,
+    ) -> Result<String, EloquentError> {
+        let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
+
+        let mut select = match aggregate {
+            Aggregate::Count => Select {
+                column: "*".to_string(),
+                function: Some(Function::Count),
+                alias: None,
+                expression: None,
+                over: None,
+            },
+            Aggregate::Function(Function::Distinct, _) => {
+                return Err(EloquentError::NotAnAggregateFunction(
+                    Function::Distinct.to_string(),
+                ))
+            }
+            Aggregate::Function(function, column) => Select {
+                column: column.clone(),
+                function: Some(*function),
+                alias: None,
+                expression: None,
+                over: None,
+            },
+            Aggregate::Exists => {
+                let mut rows = String::new();
+
+                Self::rows(builder, table, &mut rows, bindings)?;
+
+                sql.push_str("SELECT ");
+                sql.push_str(&bindings.dialect().exists_expression(&rows));
+
+                return Ok(sql.to_string());
+            }
+        };
+
+        // grouped, aggregated or distinct rows can only be aggregated after they are produced
+        let grouped = !builder.group_by.is_empty()
+            || !builder.havings.is_empty()
+            || !builder.having_closures.is_empty()
+            || !builder.set_operations.is_empty()
+            || builder
+                .selects
+                .iter()
+                .any(|select| select.function.is_some());
+
+        if grouped {
+            // the subquery exposes the column without the qualifier of its table
+            if let Some((_, column)) = select.column.rsplit_once('.') {
+                select.column = column.to_string();
+            }
+
+            if let Aggregate::Function(_, column) = aggregate {
+                if !Self::is_exposed(builder, &select.column) {
+                    return Err(EloquentError::UnknownColumn(column.clone()));
+                }
+            }
+
+            sql.push_str(&format!(
+                "SELECT {} FROM (",
+                select.format_column_name(bindings)?
+            ));
+            Self::rows(builder, table, sql, bindings)?;
+            sql.push_str(") AS aggregate_table");
+        } else {
+            selects::format(
+                table,
+                builder.table_alias.as_ref(),
+                &[select],
+                None,
+                None,
+                sql,
+                bindings,
+            )?;
+            joins::format(&builder.joins, sql, bindings)?;
+            conditions::format(&builder.conditions, &builder.closures, None, sql, bindings)?;
+        }
+
+        Ok(sql.to_string())
+    }
+
+    /// Whether the rows of the query have a column with the given name, by alias or plain column.
+    fn is_exposed(builder: &QueryBuilder, column: &str) -> bool {
+        column == "*"
+            || builder.selects.is_empty()
+            || builder.selects.iter().any(|select| match &select.alias {
+                Some(alias) => alias == column,
+                None if select.function.is_some() || select.over.is_some() => false,
+                None => {
+                    let name = select.column.rsplit('.').next().unwrap_or_default();
+
+                    name == "*" || name == column
+                }
+            })
+    }
+
+    /// The selected rows of the query without their order, limit and offset.
+    fn rows(
+        builder: &QueryBuilder,
+        table: &str,
+        sql: &mut String,
+        bindings: &mut Bindings,
+    ) -> Result<(), EloquentError> {
+        selects::format(
+            table,
+            builder.table_alias.as_ref(),
+            &builder.selects,
+            None,
+            None,
+            sql,
+            bindings,
+        )?;
+        joins::format(&builder.joins, sql, bindings)?;
+        conditions::format(&builder.conditions, &builder.closures, None, sql, bindings)?;
+        group_by::format(&builder.group_by, sql, bindings);
+        havings::format(&builder.havings, &builder.having_closures, sql, bindings)?;
+        set_operations::format(&builder.set_operations, sql, bindings)?;
+
+        Ok(())
+    }
+}
@@ -1,24 +1,25 @@
 use crate::{
-    compilers::{conditions, delete, havings, joins},
+    compilers::{conditions, delete, havings, joins, returning},
     error::EloquentError,
-    SqlBuilder,
+    Bindings, SqlBuilder,
 };
 
 pub struct DeleteBuilder;
 
 impl SqlBuilder for DeleteBuilder {
-    fn build<'a>(
-        builder: &'a crate::QueryBuilder,
+    fn build(
+        builder: &crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<dyn crate::ToSql + 'static>>,
+        bindings: &mut Bindings,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-        delete::format(table, sql);
+        delete::format(table, builder.table_alias.as_ref(), sql, bindings);
 
-        joins::format(&builder.joins, sql);
-        conditions::format(&builder.conditions, &builder.closures, &None, sql, params)?;
-        havings::format(&builder.havings, sql)?;
+        joins::format(&builder.joins, sql, bindings)?;
+        conditions::format(&builder.conditions, &builder.closures, None, sql, bindings)?;
+        havings::format(&builder.havings, &builder.having_closures, sql, bindings)?;
+        returning::format(&builder.returning, sql, bindings)?;
 
         Ok(sql.to_string())
     }
@@ -1,16 +1,25 @@
-use crate::{compilers::inserts, error::EloquentError, SqlBuilder};
+use crate::{
+    compilers::{inserts, returning, upserts},
+    error::EloquentError,
+    Bindings, SqlBuilder,
+};
 
 pub struct InsertBuilder;
 
 impl SqlBuilder for InsertBuilder {
-    fn build<'a>(
-        builder: &'a crate::QueryBuilder,
+    fn build(
+        builder: &crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<dyn crate::ToSql + 'static>>,
+        bindings: &mut Bindings,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-        inserts::format(table, &builder.inserts, sql, params);
+        match &builder.insert_using {
+            Some(insert_using) => inserts::format_using(table, insert_using, sql, bindings)?,
+            None => inserts::format(table, &builder.inserts, sql, bindings)?,
+        };
+        upserts::format(&builder.upsert, &builder.insert_columns(), sql, bindings)?;
+        returning::format(&builder.returning, sql, bindings)?;
 
         Ok(sql.to_string())
     }
@@ -1,3 +1,4 @@
+pub mod aggregate;
 pub mod delete;
 pub mod insert;
 pub mod select;
@@ -1,33 +1,64 @@
 use crate::{
-    compilers::{conditions, group_by, havings, joins, limit, offset, order_by, selects},
+    compilers::{conditions, group_by, havings, joins, limit, order_by, selects, set_operations},
     error::EloquentError,
-    SqlBuilder,
+    Bindings, SqlBuilder,
 };
 
 pub struct SelectBuilder;
 
 impl SqlBuilder for SelectBuilder {
-    fn build<'a>(
-        builder: &'a crate::QueryBuilder,
+    fn build(
+        builder: &crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<dyn crate::ToSql + 'static>>,
+        bindings: &mut Bindings,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-        selects::format(table, &builder.selects, sql);
-        joins::format(&builder.joins, sql);
+        if let Some((page, per_page)) = builder.page_out_of_range {
+            return Err(EloquentError::PageOutOfRange(page, per_page));
+        }
+
+        let row_limit = builder
+            .paginate
+            .as_ref()
+            .map(|keyset| keyset.per_page())
+            .or(builder.limit);
+
+        let row_offset = limit::offset(
+            row_limit,
+            builder.offset,
+            !builder.set_operations.is_empty(),
+            bindings,
+        );
+
+        selects::format(
+            table,
+            builder.table_alias.as_ref(),
+            &builder.selects,
+            row_limit,
+            row_offset,
+            sql,
+            bindings,
+        )?;
+        joins::format(&builder.joins, sql, bindings)?;
         conditions::format(
             &builder.conditions,
             &builder.closures,
-            &builder.paginate,
+            builder.paginate.as_ref(),
             sql,
-            params,
+            bindings,
         )?;
-        group_by::format(&builder.group_by, sql);
-        havings::format(&builder.havings, sql)?;
-        order_by::format(&builder.order_by, sql);
-        limit::format(&builder.limit, &builder.paginate, sql);
-        offset::format(&builder.offset, sql);
+        group_by::format(&builder.group_by, sql, bindings);
+        havings::format(&builder.havings, &builder.having_closures, sql, bindings)?;
+        set_operations::format(&builder.set_operations, sql, bindings)?;
+        order_by::format(&builder.order_by, builder.paginate.as_ref(), sql, bindings)?;
+        limit::format(
+            row_limit,
+            row_offset,
+            !builder.order_by.is_empty() || builder.paginate.is_some(),
+            sql,
+            bindings,
+        );
 
         Ok(sql.to_string())
     }
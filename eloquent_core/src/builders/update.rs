@@ -1,23 +1,34 @@
 use crate::{
-    compilers::{conditions, havings, joins, updates},
+    compilers::{conditions, havings, joins, returning, updates},
     error::EloquentError,
-    SqlBuilder,
+    Bindings, SqlBuilder,
 };
 
 pub struct UpdateBuilder;
 
 impl SqlBuilder for UpdateBuilder {
-    fn build<'a>(
-        builder: &'a crate::QueryBuilder,
+    fn build(
+        builder: &crate::QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<dyn crate::ToSql + 'static>>,
+        bindings: &mut Bindings,
     ) -> Result<String, EloquentError> {
         let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-        updates::format(table, &builder.updates, sql, params);
-        joins::format(&builder.joins, sql);
-        conditions::format(&builder.conditions, &builder.closures, &None, sql, params)?;
-        havings::format(&builder.havings, sql)?;
+        if let Some(table) = &builder.missing_primary_key {
+            return Err(EloquentError::MissingPrimaryKey(table.to_string()));
+        }
+
+        updates::format(
+            table,
+            builder.table_alias.as_ref(),
+            &builder.updates,
+            sql,
+            bindings,
+        )?;
+        joins::format(&builder.joins, sql, bindings)?;
+        conditions::format(&builder.conditions, &builder.closures, None, sql, bindings)?;
+        havings::format(&builder.havings, &builder.having_closures, sql, bindings)?;
+        returning::format(&builder.returning, sql, bindings)?;
 
         Ok(sql.to_string())
     }
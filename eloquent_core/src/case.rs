@@ -0,0 +1,120 @@
+use crate::{
+    compilers::conditions, error::EloquentError, Bindings, Condition, Generic, Logic, QueryBuilder,
+    Selectable, ToSql,
+};
+
+/// A `CASE WHEN ... THEN ... ELSE ... END` expression.
+///
+/// The conditions of each branch are built with the same methods as the conditions of a query.
+///
+/// ```
+/// use eloquent_core::{Case, QueryBuilder};
+///
+/// let status = Case::new()
+///     .when(|query| query.r#where("status", "cancelled"), "Cancelled")
+///     .when(|query| query.where_gt("delay_in_min", 15), "Delayed")
+///     .r#else("On time");
+///
+/// let result = QueryBuilder::new()
+///     .table("flights")
+///     .select("flight_number")
+///     .select_as(status, "status_label");
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "SELECT flight_number, CASE WHEN status = 'cancelled' THEN 'Cancelled' WHEN delay_in_min > 15 THEN 'Delayed' ELSE 'On time' END AS status_label FROM flights"
+/// );
+/// ```
+pub struct Case {
+    branches: Vec<CaseBranch>,
+    otherwise: Option<Box<dyn ToSql>>,
+}
+
+struct CaseBranch {
+    conditions: Vec<Condition>,
+    closures: Vec<(Logic, Vec<Condition>)>,
+    value: Box<dyn ToSql>,
+}
+
+impl Case {
+    pub fn new() -> Self {
+        Self {
+            branches: Vec::new(),
+            otherwise: None,
+        }
+    }
+
+    /// Add a `WHEN ... THEN ...` branch, the conditions are built inside the closure.
+    pub fn when<F>(mut self, closure: F, value: impl ToSql + 'static) -> Self
+    where
+        F: FnOnce(QueryBuilder) -> QueryBuilder,
+    {
+        let nested_builder = closure(QueryBuilder::new());
+
+        self.branches.push(CaseBranch {
+            conditions: nested_builder.conditions,
+            closures: nested_builder.closures,
+            value: Box::new(value),
+        });
+
+        self
+    }
+
+    /// Set the value of the `ELSE` branch.
+    pub fn r#else(mut self, value: impl ToSql + 'static) -> Self {
+        self.otherwise = Some(Box::new(value));
+
+        self
+    }
+}
+
+impl Default for Case {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ToSql for Case {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        self.to_sql_bound(&mut Bindings::inline(&Generic))
+    }
+
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        if self.branches.is_empty() {
+            return Err(EloquentError::MissingCaseBranches);
+        }
+
+        let mut sql = String::from("CASE");
+
+        for branch in &self.branches {
+            sql.push_str(" WHEN ");
+            sql.push_str(&conditions::format_conditions(
+                &branch.conditions,
+                &branch.closures,
+                bindings,
+            )?);
+            sql.push_str(" THEN ");
+            sql.push_str(&branch.value.to_sql_bound(bindings)?);
+        }
+
+        if let Some(otherwise) = &self.otherwise {
+            sql.push_str(" ELSE ");
+            sql.push_str(&otherwise.to_sql_bound(bindings)?);
+        }
+
+        sql.push_str(" END");
+
+        Ok(sql)
+    }
+}
+
+impl Selectable for Case {
+    fn to_select_column(&self) -> String {
+        // the expression is compiled again with the query, which reports the error
+        self.to_sql().unwrap_or_default()
+    }
+
+    fn to_select_expression(self) -> Option<Box<dyn ToSql>> {
+        Some(Box::new(self))
+    }
+}
@@ -14,7 +14,7 @@ impl PerformChecks for CannotApplyClauseOnDelete {
             ));
         }
 
-        if !builder.havings.is_empty() {
+        if !builder.havings.is_empty() || !builder.having_closures.is_empty() {
             return Err(EloquentError::CannotApplyClauseOnDelete(
                 "HAVING".to_string(),
             ));
@@ -24,6 +24,24 @@ impl PerformChecks for CannotApplyClauseOnDelete {
             return Err(EloquentError::CannotApplyClauseOnDelete("JOIN".to_string()));
         }
 
+        if let Some(set_operation) = builder.set_operations.first() {
+            return Err(EloquentError::CannotApplyClauseOnDelete(
+                set_operation.operator.to_string(),
+            ));
+        }
+
+        if builder.upsert.is_some() {
+            return Err(EloquentError::CannotApplyClauseOnDelete(
+                "ON CONFLICT".to_string(),
+            ));
+        }
+
+        if let Some(aggregate) = &builder.aggregate {
+            return Err(EloquentError::CannotApplyClauseOnDelete(
+                aggregate.to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -4,7 +4,7 @@ pub struct CannotApplyClauseOnInsert;
 
 impl PerformChecks for CannotApplyClauseOnInsert {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
-        if builder.inserts.is_empty() {
+        if builder.inserts.is_empty() && builder.insert_using.is_none() {
             return Ok(());
         }
 
@@ -26,7 +26,7 @@ impl PerformChecks for CannotApplyClauseOnInsert {
             ));
         }
 
-        if !builder.havings.is_empty() {
+        if !builder.havings.is_empty() || !builder.having_closures.is_empty() {
             return Err(EloquentError::CannotApplyClauseOnInsert(
                 "HAVING".to_string(),
             ));
@@ -48,6 +48,29 @@ impl PerformChecks for CannotApplyClauseOnInsert {
             ));
         }
 
+        if let Some(set_operation) = builder.set_operations.first() {
+            return Err(EloquentError::CannotApplyClauseOnInsert(
+                set_operation.operator.to_string(),
+            ));
+        }
+
+        if let Some(upsert) = &builder.upsert {
+            if let Some(column) = upsert
+                .target
+                .iter()
+                .chain(upsert.updates.iter())
+                .find(|column| !builder.insert_columns().contains(column))
+            {
+                return Err(EloquentError::UpsertColumnNotInserted(column.to_string()));
+            }
+        }
+
+        if let Some(aggregate) = &builder.aggregate {
+            return Err(EloquentError::CannotApplyClauseOnInsert(
+                aggregate.to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -72,4 +95,22 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_cannot_resolve_conflict_on_column_that_is_not_inserted() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .insert("origin_airport", "AMS")
+            .on_conflict("flight_number")
+            .do_update("origin_airport")
+            .sql();
+
+        match result {
+            Err(EloquentError::UpsertColumnNotInserted(column)) => {
+                assert_eq!(column, "flight_number")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
 }
@@ -0,0 +1,47 @@
+use crate::{error::EloquentError, Action, PerformChecks, QueryBuilder};
+
+pub struct CannotApplyClauseOnSelect;
+
+impl PerformChecks for CannotApplyClauseOnSelect {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if builder.get_action() != Action::Select {
+            return Ok(());
+        }
+
+        if !builder.returning.is_empty() {
+            return Err(EloquentError::CannotApplyClauseOnSelect(
+                "RETURNING".to_string(),
+            ));
+        }
+
+        if builder.upsert.is_some() {
+            return Err(EloquentError::CannotApplyClauseOnSelect(
+                "ON CONFLICT".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder};
+
+    #[test]
+    fn test_cannot_apply_clause_on_select() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("flight_number")
+            .returning("id")
+            .sql();
+
+        match result {
+            Err(EloquentError::CannotApplyClauseOnSelect(clause)) => {
+                assert_eq!(clause, "RETURNING")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -14,7 +14,7 @@ impl PerformChecks for CannotApplyClauseOnUpdate {
             ));
         }
 
-        if !builder.havings.is_empty() {
+        if !builder.havings.is_empty() || !builder.having_closures.is_empty() {
             return Err(EloquentError::CannotApplyClauseOnUpdate(
                 "HAVING".to_string(),
             ));
@@ -24,6 +24,24 @@ impl PerformChecks for CannotApplyClauseOnUpdate {
             return Err(EloquentError::CannotApplyClauseOnUpdate("JOIN".to_string()));
         }
 
+        if let Some(set_operation) = builder.set_operations.first() {
+            return Err(EloquentError::CannotApplyClauseOnUpdate(
+                set_operation.operator.to_string(),
+            ));
+        }
+
+        if builder.upsert.is_some() {
+            return Err(EloquentError::CannotApplyClauseOnUpdate(
+                "ON CONFLICT".to_string(),
+            ));
+        }
+
+        if let Some(aggregate) = &builder.aggregate {
+            return Err(EloquentError::CannotApplyClauseOnUpdate(
+                aggregate.to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -48,4 +66,21 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_cannot_count_update() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .update("origin_airport", "AMS")
+            .into_count()
+            .sql();
+
+        match result {
+            Err(EloquentError::CannotApplyClauseOnUpdate(clause)) => {
+                assert_eq!(clause, "COUNT")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
 }
@@ -0,0 +1,112 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct CannotCombineNonSelectOrOrderedQueries;
+
+impl PerformChecks for CannotCombineNonSelectOrOrderedQueries {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        for set_operation in &builder.set_operations {
+            if !set_operation.query.is_select() {
+                return Err(EloquentError::CannotCombineNonSelectQuery(
+                    set_operation.operator.to_string(),
+                ));
+            }
+
+            // a WITH is invalid inside a branch, an ORDER BY or LIMIT would silently apply to the
+            // whole combined result
+            if let Some(clause) = set_operation.query.ordering_clause() {
+                return Err(EloquentError::CannotApplyClauseOnSetOperationBranch(clause));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, SubqueryBuilder};
+
+    #[test]
+    fn test_cannot_combine_non_select_query() {
+        let result = QueryBuilder::new()
+            .table("departures")
+            .select("flight_number")
+            .union(
+                QueryBuilder::new()
+                    .table("arrivals")
+                    .insert("flight_number", 1),
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::CannotCombineNonSelectQuery(operator)) => {
+                assert_eq!(operator, "UNION")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_cannot_apply_limit_on_set_operation_branch() {
+        let result = QueryBuilder::new()
+            .table("departures")
+            .select("flight_number")
+            .union(
+                SubqueryBuilder::new()
+                    .table("arrivals")
+                    .select("flight_number")
+                    .limit(3),
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::CannotApplyClauseOnSetOperationBranch(clause)) => {
+                assert_eq!(clause, "LIMIT")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_cannot_apply_with_on_set_operation_branch() {
+        let delayed = SubqueryBuilder::new()
+            .table("arrivals")
+            .where_gt("delay_in_min", 30);
+
+        let query_branch = QueryBuilder::new()
+            .table("departures")
+            .select("flight_number")
+            .union(
+                QueryBuilder::new()
+                    .with("delayed", delayed)
+                    .table("delayed")
+                    .select("flight_number"),
+            );
+
+        let cancelled = SubqueryBuilder::new()
+            .table("arrivals")
+            .r#where("status", "cancelled");
+
+        let subquery_branch = QueryBuilder::new()
+            .table("departures")
+            .select("flight_number")
+            .union(
+                SubqueryBuilder::new()
+                    .with("cancelled", cancelled)
+                    .table("cancelled")
+                    .select("flight_number"),
+            );
+
+        for result in [query_branch.sql(), subquery_branch.sql()] {
+            match result {
+                Err(EloquentError::CannotApplyClauseOnSetOperationBranch(clause)) => {
+                    assert_eq!(clause, "WITH")
+                }
+                Err(_error) => panic!(),
+                Ok(_value) => panic!(),
+            }
+        }
+    }
+}
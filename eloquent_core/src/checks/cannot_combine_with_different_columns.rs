@@ -0,0 +1,52 @@
+use crate::{error::EloquentError, Combinable, PerformChecks, QueryBuilder};
+
+pub struct CannotCombineWithDifferentColumns;
+
+impl PerformChecks for CannotCombineWithDifferentColumns {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let Some(column_count) = builder.selected_column_count() else {
+            return Ok(());
+        };
+
+        let inconsistent_branch = builder.set_operations.iter().find(|set_operation| {
+            set_operation
+                .query
+                .selected_column_count()
+                .is_some_and(|count| count != column_count)
+        });
+
+        if let Some(set_operation) = inconsistent_branch {
+            return Err(EloquentError::InconsistentSetOperationColumns(
+                set_operation.operator.to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, SubqueryBuilder};
+
+    #[test]
+    fn test_cannot_combine_with_different_columns() {
+        let result = QueryBuilder::new()
+            .table("departures")
+            .select("flight_number")
+            .union_all(
+                SubqueryBuilder::new()
+                    .table("arrivals")
+                    .select(vec!["flight_number", "arrival_time"]),
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::InconsistentSetOperationColumns(operator)) => {
+                assert_eq!(operator, "UNION ALL")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
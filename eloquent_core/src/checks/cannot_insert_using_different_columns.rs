@@ -0,0 +1,44 @@
+use crate::{error::EloquentError, Combinable, PerformChecks, QueryBuilder};
+
+pub struct CannotInsertUsingDifferentColumns;
+
+impl PerformChecks for CannotInsertUsingDifferentColumns {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let Some(insert_using) = &builder.insert_using else {
+            return Ok(());
+        };
+
+        if let Some(column_count) = insert_using.query.selected_column_count() {
+            if column_count != insert_using.columns.len() {
+                return Err(EloquentError::InconsistentInsertUsingColumns);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, SubqueryBuilder};
+
+    #[test]
+    fn test_cannot_insert_using_different_columns() {
+        let subquery = SubqueryBuilder::new().table("flights").select(vec![
+            "id",
+            "flight_number",
+            "origin_airport",
+        ]);
+
+        let result = QueryBuilder::new()
+            .table("archived_flights")
+            .insert_using(vec!["flight_id", "flight_number"], subquery)
+            .sql();
+
+        match result {
+            Err(EloquentError::InconsistentInsertUsingColumns) => (),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{error::EloquentError, JoinSource, PerformChecks, QueryBuilder, SubqueryBuilder};
+
+pub struct CteReferencedBeforeDefinition;
+
+impl PerformChecks for CteReferencedBeforeDefinition {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        for (i, cte) in builder.ctes.iter().enumerate() {
+            // a recursive CTE may reference itself, all other CTEs only those defined before them
+            let undefined = builder
+                .ctes
+                .iter()
+                .skip(i)
+                .filter(|other| !(other.name == cte.name && cte.recursive.is_some()));
+
+            for other in undefined {
+                let references = std::iter::once(&cte.query)
+                    .chain(cte.recursive.as_ref())
+                    .any(|query| references_table(query, &other.name));
+
+                if references {
+                    return Err(EloquentError::UndefinedCte(other.name.clone()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn references_table(query: &SubqueryBuilder, table: &String) -> bool {
+    query.table.as_ref() == Some(table)
+        || query
+            .joins
+            .iter()
+            .any(|join| matches!(&join.source, JoinSource::Table(name) if name == table))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, SubqueryBuilder};
+
+    #[test]
+    fn test_cte_referenced_before_definition() {
+        let result = QueryBuilder::new()
+            .with(
+                "late_arrivals",
+                SubqueryBuilder::new()
+                    .table("delayed_flights")
+                    .where_gt("delay", 60),
+            )
+            .with(
+                "delayed_flights",
+                SubqueryBuilder::new()
+                    .table("flights")
+                    .where_gt("delay", 30),
+            )
+            .table("late_arrivals")
+            .sql();
+
+        match result {
+            Err(EloquentError::UndefinedCte(name)) => assert_eq!(name, "delayed_flights"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_recursive_cte_references_itself() {
+        let result = QueryBuilder::new()
+            .with_recursive(
+                "hierarchy",
+                vec!["id", "manager_id"],
+                SubqueryBuilder::new()
+                    .table("employees")
+                    .select(vec!["id", "manager_id"]),
+                SubqueryBuilder::new()
+                    .table("employees")
+                    .select(vec!["employees.id", "employees.manager_id"])
+                    .join("hierarchy", "employees.manager_id", "hierarchy.id"),
+            )
+            .table("hierarchy")
+            .sql();
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::EloquentError, schema_builder::SchemaStatement, table_definition::AlterOperation,
+    PerformChecks, SchemaBuilder,
+};
+
+pub struct DuplicatedColumnDefinitions;
+
+impl PerformChecks<SchemaBuilder> for DuplicatedColumnDefinitions {
+    fn check(builder: &SchemaBuilder) -> Result<(), EloquentError> {
+        for statement in &builder.statements {
+            let columns = match statement {
+                SchemaStatement::Create(definition) => definition
+                    .columns
+                    .iter()
+                    .map(|column| &column.name)
+                    .collect::<Vec<&String>>(),
+                SchemaStatement::Alter(alter_table) => alter_table
+                    .operations
+                    .iter()
+                    .filter_map(|operation| match operation {
+                        AlterOperation::Add(column) => Some(&column.name),
+                        _ => None,
+                    })
+                    .collect::<Vec<&String>>(),
+                SchemaStatement::Drop(..)
+                | SchemaStatement::CreateIndex(..)
+                | SchemaStatement::DropIndex(..) => continue,
+            };
+
+            let mut seen = HashSet::new();
+
+            for column in columns {
+                if !seen.insert(column) {
+                    return Err(EloquentError::DuplicatedColumnNames(column.to_string()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, ColumnDefinition, ColumnType, SchemaBuilder};
+
+    #[test]
+    fn test_duplicated_column_definitions() {
+        let result = SchemaBuilder::new()
+            .create_table("flights", |table| {
+                table
+                    .column(ColumnDefinition::new("id", ColumnType::Integer))
+                    .column(ColumnDefinition::new("id", ColumnType::BigInteger))
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::DuplicatedColumnNames(column)) => {
+                assert_eq!(column, "id");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -8,9 +8,16 @@ impl PerformChecks for DuplicatedColumns {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
         let mut seen = HashSet::new();
 
+        // an aliased column is identified by its alias, so the same column can be selected twice
+        // under different aliases, e.g. from both sides of a self-join
         for select in &builder.selects {
-            if !seen.insert((&select.column, &select.function)) {
-                return Err(EloquentError::DuplicatedColumnNames(select.column.clone()));
+            let (name, function) = match &select.alias {
+                Some(alias) => (alias, &None),
+                None => (&select.column, &select.function),
+            };
+
+            if !seen.insert((name, function)) {
+                return Err(EloquentError::DuplicatedColumnNames(name.clone()));
             }
         }
 
@@ -36,4 +43,40 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_duplicated_aliases() {
+        let result = QueryBuilder::new()
+            .table_as("flights", "f")
+            .join_as("airports", "origin", "f.origin_airport", "origin.code")
+            .join_as(
+                "airports",
+                "destination",
+                "f.destination_airport",
+                "destination.code",
+            )
+            .select_as("origin.name", "airport")
+            .select_as("destination.name", "airport")
+            .sql();
+
+        match result {
+            Err(EloquentError::DuplicatedColumnNames(column)) => assert_eq!(column, "airport"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_same_column_with_different_aliases() {
+        let result = QueryBuilder::new()
+            .table_as("flights", "f")
+            .select_as("f.origin_airport", "origin")
+            .select_as("f.origin_airport", "airport")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT f.origin_airport AS origin, f.origin_airport AS airport FROM flights AS f"
+        );
+    }
 }
@@ -9,7 +9,7 @@ impl PerformChecks for DuplicatedConditions {
         let mut seen = HashSet::new();
 
         for condition in &builder.conditions {
-            if condition.logic == Logic::Or {
+            if condition.logic == Logic::Or || !condition.has_field() {
                 continue;
             }
 
@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct DuplicatedCteNames;
+
+impl PerformChecks for DuplicatedCteNames {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let mut seen = HashSet::new();
+
+        for cte in &builder.ctes {
+            if !seen.insert(&cte.name) {
+                return Err(EloquentError::DuplicatedCteNames(cte.name.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, SubqueryBuilder};
+
+    #[test]
+    fn test_duplicated_cte_names() {
+        let result = QueryBuilder::new()
+            .with(
+                "delayed_flights",
+                SubqueryBuilder::new()
+                    .table("flights")
+                    .where_gt("delay", 30),
+            )
+            .with(
+                "delayed_flights",
+                SubqueryBuilder::new()
+                    .table("flights")
+                    .where_gt("delay", 60),
+            )
+            .table("delayed_flights")
+            .sql();
+
+        match result {
+            Err(EloquentError::DuplicatedCteNames(name)) => assert_eq!(name, "delayed_flights"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{error::EloquentError, schema_builder::SchemaStatement, PerformChecks, SchemaBuilder};
+
+pub struct ForeignKeyToUndeclaredColumn;
+
+impl PerformChecks<SchemaBuilder> for ForeignKeyToUndeclaredColumn {
+    fn check(builder: &SchemaBuilder) -> Result<(), EloquentError> {
+        let schema = builder.active_schema();
+
+        for statement in &builder.statements {
+            let SchemaStatement::Create(definition) = statement else {
+                continue;
+            };
+
+            for (_, table, reference) in definition.references() {
+                let is_declared = if let Some(created_table) = builder.created_table(table) {
+                    created_table
+                        .columns
+                        .iter()
+                        .any(|column| &column.name == reference)
+                } else if let Some(schema) = &schema {
+                    schema
+                        .find_table(table)
+                        .ok_or_else(|| EloquentError::UnknownTable(table.clone()))?
+                        .has_column(reference)
+                } else {
+                    true
+                };
+
+                if !is_declared {
+                    return Err(EloquentError::ForeignKeyToUndeclaredColumn(format!(
+                        "{}.{}",
+                        table, reference
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::EloquentError, ColumnDefinition, ColumnType, Schema, SchemaBuilder, TableSchema,
+    };
+
+    #[test]
+    fn test_foreign_key_to_undeclared_column() {
+        let result = SchemaBuilder::new()
+            .create_table("airports", |table| {
+                table.column(ColumnDefinition::new("code", ColumnType::Varchar(3)).primary_key())
+            })
+            .create_table("flights", |table| {
+                table.column(
+                    ColumnDefinition::new("origin", ColumnType::Varchar(3))
+                        .references("airports", "id"),
+                )
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::ForeignKeyToUndeclaredColumn(column)) => {
+                assert_eq!(column, "airports.id");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_foreign_key_to_schema_column() {
+        let schema = Schema::new()
+            .table(TableSchema::new("airports").column("code", ColumnType::Varchar(3)));
+
+        let result = SchemaBuilder::new()
+            .schema(schema)
+            .create_table("flights", |table| {
+                table
+                    .column(ColumnDefinition::new("origin", ColumnType::Varchar(3)))
+                    .foreign_key("origin", "airports", "code")
+            })
+            .sql();
+
+        assert!(result.is_ok());
+    }
+}
@@ -5,13 +5,18 @@ pub struct GroupByWithoutSelectedOrAggregateFunction;
 impl PerformChecks for GroupByWithoutSelectedOrAggregateFunction {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
         for group_by in &builder.group_by {
-            if !builder.selects.iter().any(|select| {
-                &select.format_column_name_without_alias() == group_by
-                    || select
-                        .alias
-                        .as_ref()
-                        .map(|alias| alias == group_by)
-                        .unwrap_or(false)
+            if !builder.selects.iter().any(|select| match &select.over {
+                // window functions are evaluated after grouping, so they can't be grouped by
+                // themselves but their partitions and orders can reference the grouped columns
+                Some(over) => over.window.references(group_by),
+                None => {
+                    &select.format_column_name_without_alias() == group_by
+                        || select
+                            .alias
+                            .as_ref()
+                            .map(|alias| alias == group_by)
+                            .unwrap_or(false)
+                }
             }) {
                 return Err(EloquentError::GroupByWithNonSelectedOrAggregateFunction(
                     group_by.clone(),
@@ -25,7 +30,7 @@ impl PerformChecks for GroupByWithoutSelectedOrAggregateFunction {
 
 #[cfg(test)]
 mod tests {
-    use crate::{error::EloquentError, QueryBuilder};
+    use crate::{error::EloquentError, QueryBuilder, Window};
 
     #[test]
     fn test_group_by_without_selected_or_aggregate_function() {
@@ -42,4 +47,37 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_group_by_with_windowed_column() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_sum("price", "revenue")
+            .select_rank(Window::new().order_by_desc("origin"), "origin_rank")
+            .group_by("origin")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT SUM(price) AS revenue, RANK() OVER (ORDER BY origin DESC) AS origin_rank FROM flights GROUP BY origin"
+        );
+    }
+
+    #[test]
+    fn test_group_by_window_function() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_row_number(Window::new().order_by_asc("origin"), "row_number")
+            .group_by(vec!["origin", "row_number"])
+            .sql();
+
+        match result {
+            Err(EloquentError::GroupByWithNonSelectedOrAggregateFunction(column)) => {
+                assert_eq!(column, "row_number")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
 }
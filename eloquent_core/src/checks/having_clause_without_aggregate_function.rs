@@ -4,17 +4,26 @@ pub struct HavingClauseWithoutAggregateFunction;
 
 impl PerformChecks for HavingClauseWithoutAggregateFunction {
     fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
-        for having in &builder.havings {
-            for condition in &having.conditions {
-                if !builder.selects.iter().any(|select| {
-                    (select.format_column_name_without_alias() == condition.field
-                        && select.function.is_some())
-                        || select.alias == Some(condition.field.clone())
-                }) {
-                    return Err(EloquentError::HavingClauseWithoutAggregateFunction(
-                        condition.field.clone(),
-                    ));
-                }
+        let conditions = builder.havings.iter().chain(
+            builder
+                .having_closures
+                .iter()
+                .flat_map(|(_, conditions)| conditions),
+        );
+
+        for condition in conditions {
+            if condition.function.is_some() {
+                continue;
+            }
+
+            if !builder.selects.iter().any(|select| {
+                (select.format_column_name_without_alias() == condition.field
+                    && select.function.is_some())
+                    || select.alias == Some(condition.field.clone())
+            }) {
+                return Err(EloquentError::HavingClauseWithoutAggregateFunction(
+                    condition.field.clone(),
+                ));
             }
         }
 
@@ -41,4 +50,21 @@ mod tests {
             Ok(_value) => panic!(),
         }
     }
+
+    #[test]
+    fn test_having_closure_without_aggregate_function() {
+        let result: Result<_, EloquentError> = QueryBuilder::new()
+            .table("flights")
+            .select_count("id", "total")
+            .having_closure(|query| query.having_gt("total", 5).or_having("origin", "AMS"))
+            .sql();
+
+        match result {
+            Err(EloquentError::HavingClauseWithoutAggregateFunction(column)) => {
+                assert_eq!(column, "origin")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
 }
@@ -0,0 +1,134 @@
+use crate::{
+    error::EloquentError, schema::ResolvedColumn, schema::SchemaScope, Operator, PerformChecks,
+    QueryBuilder, ToSql,
+};
+
+pub struct IncompatibleColumnTypes;
+
+impl PerformChecks for IncompatibleColumnTypes {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let Some(schema) = builder.active_schema() else {
+            return Ok(());
+        };
+
+        let scope = schema.scope(builder)?;
+
+        for insert in &builder.inserts {
+            check_values(&scope, &insert.column, &insert.values)?;
+        }
+
+        for update in &builder.updates {
+            check_values(&scope, &update.column, std::slice::from_ref(&update.value))?;
+        }
+
+        let conditions = builder
+            .conditions
+            .iter()
+            .chain(builder.closures.iter().flat_map(|(_, closure)| closure))
+            .filter(|condition| {
+                matches!(
+                    condition.operator,
+                    Operator::Equal
+                        | Operator::NotEqual
+                        | Operator::GreaterThan
+                        | Operator::GreaterThanOrEqual
+                        | Operator::LessThan
+                        | Operator::LessThanOrEqual
+                        | Operator::Between
+                        | Operator::In
+                        | Operator::NotIn
+                )
+            });
+
+        for condition in conditions {
+            check_values(&scope, &condition.field, &condition.values)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn check_values(
+    scope: &SchemaScope,
+    column: &str,
+    values: &[Box<dyn ToSql>],
+) -> Result<(), EloquentError> {
+    let ResolvedColumn::Found(column_schema) = scope.resolve(column) else {
+        return Ok(());
+    };
+
+    for value in values.iter().filter_map(|value| value.to_value()) {
+        if !column_schema.accepts(&value) {
+            return Err(EloquentError::IncompatibleColumnType(
+                column.to_string(),
+                column_schema.column_type.to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, ColumnType, QueryBuilder, Schema, TableSchema};
+
+    fn schema() -> Schema {
+        Schema::new().table(
+            TableSchema::new("flights")
+                .column("id", ColumnType::Integer)
+                .column("flight_number", ColumnType::Text)
+                .nullable_column("delay_in_min", ColumnType::Integer),
+        )
+    }
+
+    #[test]
+    fn test_incompatible_insert_value() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table("flights")
+            .insert("id", "abc")
+            .insert("flight_number", "KL123")
+            .sql();
+
+        match result {
+            Err(EloquentError::IncompatibleColumnType(column, column_type)) => {
+                assert_eq!(column, "id");
+                assert_eq!(column_type, "INTEGER");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_incompatible_condition_value() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table("flights")
+            .update("delay_in_min", 15)
+            .where_in("flight_number", vec![123, 456])
+            .sql();
+
+        match result {
+            Err(EloquentError::IncompatibleColumnType(column, column_type)) => {
+                assert_eq!(column, "flight_number");
+                assert_eq!(column_type, "TEXT");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_compatible_values() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table("flights")
+            .insert("id", 1)
+            .insert("flight_number", "KL123")
+            .sql();
+
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{error::EloquentError, PerformChecks, QueryBuilder};
+
+pub struct InconsistentKeysetCursor;
+
+impl PerformChecks for InconsistentKeysetCursor {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        if let Some(keyset) = &builder.paginate {
+            if !keyset.has_columns() {
+                return Err(EloquentError::MissingKeysetColumns);
+            }
+
+            if !keyset.has_consistent_cursor() {
+                return Err(EloquentError::InconsistentCursorValues);
+            }
+
+            if keyset.has_null_cursor_value() {
+                return Err(EloquentError::NullCursorValue);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, Cursor, Keyset, QueryBuilder, Value};
+
+    #[test]
+    fn test_inconsistent_keyset_cursor() {
+        let result = QueryBuilder::new()
+            .table("posts")
+            .keyset(
+                Keyset::new(25)
+                    .order_by_desc("created_at")
+                    .order_by_desc("id")
+                    .cursor(Cursor::next(vec![Value::Int(42)])),
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::InconsistentCursorValues) => {}
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_keyset_without_columns() {
+        let result = QueryBuilder::new()
+            .table("posts")
+            .keyset(Keyset::new(25).cursor(Cursor::next(vec![])))
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingKeysetColumns) => {}
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_null_keyset_cursor_value() {
+        let result = QueryBuilder::new()
+            .table("posts")
+            .keyset(
+                Keyset::new(25)
+                    .order_by_desc("published_at")
+                    .order_by_desc("id")
+                    .cursor(Cursor::next(vec![Value::Null, Value::Int(42)])),
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::NullCursorValue) => {}
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
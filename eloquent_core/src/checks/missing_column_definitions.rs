@@ -0,0 +1,39 @@
+use crate::{error::EloquentError, schema_builder::SchemaStatement, PerformChecks, SchemaBuilder};
+
+pub struct MissingColumnDefinitions;
+
+impl PerformChecks<SchemaBuilder> for MissingColumnDefinitions {
+    fn check(builder: &SchemaBuilder) -> Result<(), EloquentError> {
+        for statement in &builder.statements {
+            if let SchemaStatement::Create(definition) = statement {
+                if definition.columns.is_empty() {
+                    return Err(EloquentError::MissingColumnDefinitions(
+                        definition.name.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, SchemaBuilder};
+
+    #[test]
+    fn test_missing_column_definitions() {
+        let result = SchemaBuilder::new()
+            .create_table("flights", |table| table.if_not_exists())
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingColumnDefinitions(table)) => {
+                assert_eq!(table, "flights");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
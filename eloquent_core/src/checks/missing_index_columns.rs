@@ -0,0 +1,37 @@
+use crate::{error::EloquentError, schema_builder::SchemaStatement, PerformChecks, SchemaBuilder};
+
+pub struct MissingIndexColumns;
+
+impl PerformChecks<SchemaBuilder> for MissingIndexColumns {
+    fn check(builder: &SchemaBuilder) -> Result<(), EloquentError> {
+        for statement in &builder.statements {
+            if let SchemaStatement::CreateIndex(index) = statement {
+                if index.columns.is_empty() {
+                    return Err(EloquentError::MissingIndexColumns(index.name.clone()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, SchemaBuilder};
+
+    #[test]
+    fn test_missing_index_columns() {
+        let result = SchemaBuilder::new()
+            .create_index("flights_origin_index", "flights", |index| index.unique())
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingIndexColumns(index)) => {
+                assert_eq!(index, "flights_origin_index");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
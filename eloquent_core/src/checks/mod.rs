@@ -1,8 +1,12 @@
 pub mod cannot_apply_clause_on_delete;
 pub mod cannot_apply_clause_on_insert;
+pub mod cannot_apply_clause_on_select;
 pub mod cannot_apply_clause_on_update;
+pub mod cannot_combine_with_different_columns;
 pub mod cannot_use_offset_limit_with_pagination;
+pub mod cte_referenced_before_definition;
 pub mod duplicated_columns;
+pub mod duplicated_cte_names;
 pub mod duplicated_conditions;
 pub mod group_by_without_selected_or_aggregate_function;
 pub mod having_clause_without_aggregate_function;
@@ -10,3 +14,15 @@ pub mod missing_table;
 pub mod multiple_crud_actions;
 pub mod order_by_without_selected_or_aggregate_function;
 pub mod cannot_insert_with_different_columns;
+pub mod cannot_insert_using_different_columns;
+pub mod unknown_tables_and_columns;
+pub mod null_comparison_on_non_nullable_column;
+pub mod incompatible_column_types;
+pub mod duplicated_column_definitions;
+pub mod missing_column_definitions;
+pub mod undeclared_key_columns;
+pub mod multiple_primary_keys;
+pub mod foreign_key_to_undeclared_column;
+pub mod missing_index_columns;
+pub mod inconsistent_keyset_cursor;
+pub mod cannot_combine_non_select_or_ordered_queries;
@@ -18,6 +18,10 @@ impl PerformChecks for MultipleCrudActions {
             crud_actions += 1;
         }
 
+        if builder.insert_using.is_some() {
+            crud_actions += 1;
+        }
+
         if builder.delete {
             crud_actions += 1;
         }
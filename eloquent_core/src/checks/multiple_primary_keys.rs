@@ -0,0 +1,52 @@
+use crate::{error::EloquentError, schema_builder::SchemaStatement, PerformChecks, SchemaBuilder};
+
+pub struct MultiplePrimaryKeys;
+
+impl PerformChecks<SchemaBuilder> for MultiplePrimaryKeys {
+    fn check(builder: &SchemaBuilder) -> Result<(), EloquentError> {
+        for statement in &builder.statements {
+            let SchemaStatement::Create(definition) = statement else {
+                continue;
+            };
+
+            let column_keys = definition
+                .columns
+                .iter()
+                .filter(|column| column.primary_key)
+                .count();
+
+            let table_keys = usize::from(!definition.primary_key.is_empty());
+
+            if column_keys + table_keys > 1 {
+                return Err(EloquentError::MultiplePrimaryKeys(definition.name.clone()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, ColumnDefinition, ColumnType, SchemaBuilder};
+
+    #[test]
+    fn test_multiple_primary_keys() {
+        let result = SchemaBuilder::new()
+            .create_table("flights", |table| {
+                table
+                    .column(ColumnDefinition::new("id", ColumnType::Integer).primary_key())
+                    .column(ColumnDefinition::new("flight_number", ColumnType::Text))
+                    .primary_key("flight_number")
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::MultiplePrimaryKeys(table)) => {
+                assert_eq!(table, "flights");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
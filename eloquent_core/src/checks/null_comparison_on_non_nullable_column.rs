@@ -0,0 +1,94 @@
+use crate::{error::EloquentError, schema::ResolvedColumn, Operator, PerformChecks, QueryBuilder};
+
+pub struct NullComparisonOnNonNullableColumn;
+
+impl PerformChecks for NullComparisonOnNonNullableColumn {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let Some(schema) = builder.active_schema() else {
+            return Ok(());
+        };
+
+        let scope = schema.scope(builder)?;
+
+        let conditions = builder
+            .conditions
+            .iter()
+            .chain(builder.closures.iter().flat_map(|(_, closure)| closure))
+            .filter(|condition| condition.operator == Operator::IsNull);
+
+        for condition in conditions {
+            if let (ResolvedColumn::Found(column), outer_joined) =
+                scope.resolve_with_nullability(&condition.field)
+            {
+                if !column.nullable && !outer_joined {
+                    return Err(EloquentError::NullComparisonOnNonNullableColumn(
+                        condition.field.clone(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, ColumnType, QueryBuilder, Schema, TableSchema};
+
+    #[test]
+    fn test_null_comparison_on_non_nullable_column() {
+        let schema = Schema::new().table(
+            TableSchema::new("flights")
+                .column("departure_time", ColumnType::Timestamp)
+                .nullable_column("arrival_time", ColumnType::Timestamp),
+        );
+
+        let result = QueryBuilder::new()
+            .schema(schema)
+            .table("flights")
+            .where_null(vec!["arrival_time", "departure_time"])
+            .sql();
+
+        match result {
+            Err(EloquentError::NullComparisonOnNonNullableColumn(column)) => {
+                assert_eq!(column, "departure_time");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_null_comparison_on_outer_joined_column() {
+        let schema = Schema::new()
+            .table(TableSchema::new("flights").column("id", ColumnType::Integer))
+            .table(
+                TableSchema::new("bookings")
+                    .column("id", ColumnType::Integer)
+                    .column("flight_id", ColumnType::Integer),
+            );
+
+        let result = QueryBuilder::new()
+            .schema(schema.clone())
+            .table("flights")
+            .select("flights.id")
+            .left_join("bookings", "bookings.flight_id", "flights.id")
+            .where_null("bookings.id")
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT flights.id FROM flights LEFT JOIN bookings ON bookings.flight_id = flights.id WHERE bookings.id IS NULL"
+        );
+
+        let result = QueryBuilder::new()
+            .schema(schema)
+            .table("flights")
+            .right_join("bookings", "bookings.flight_id", "flights.id")
+            .where_null("flights.id")
+            .sql();
+
+        assert!(result.is_ok());
+    }
+}
@@ -8,7 +8,12 @@ impl PerformChecks for OrderByWithoutSelectedOrAggregateFunction {
             return Ok(());
         }
 
-        for order_by in &builder.order_by {
+        // expressions such as `CASE` don't reference a selected column
+        for order_by in builder
+            .order_by
+            .iter()
+            .filter(|order_by| order_by.expression.is_none())
+        {
             if !builder.selects.iter().any(|select| {
                 select.format_column_name_without_alias() == order_by.column
                     || select
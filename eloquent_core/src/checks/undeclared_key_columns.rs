@@ -0,0 +1,60 @@
+use crate::{error::EloquentError, schema_builder::SchemaStatement, PerformChecks, SchemaBuilder};
+
+pub struct UndeclaredKeyColumns;
+
+impl PerformChecks<SchemaBuilder> for UndeclaredKeyColumns {
+    fn check(builder: &SchemaBuilder) -> Result<(), EloquentError> {
+        for statement in &builder.statements {
+            let SchemaStatement::Create(definition) = statement else {
+                continue;
+            };
+
+            let key_columns = definition
+                .primary_key
+                .iter()
+                .chain(definition.unique.iter().flatten())
+                .chain(
+                    definition
+                        .foreign_keys
+                        .iter()
+                        .flat_map(|foreign_key| &foreign_key.columns),
+                );
+
+            for key_column in key_columns {
+                if !definition
+                    .columns
+                    .iter()
+                    .any(|column| &column.name == key_column)
+                {
+                    return Err(EloquentError::UndeclaredColumn(key_column.clone()));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, ColumnDefinition, ColumnType, SchemaBuilder};
+
+    #[test]
+    fn test_undeclared_key_columns() {
+        let result = SchemaBuilder::new()
+            .create_table("bookings", |table| {
+                table
+                    .column(ColumnDefinition::new("flight_id", ColumnType::Integer))
+                    .primary_key(vec!["flight_id", "seat"])
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::UndeclaredColumn(column)) => {
+                assert_eq!(column, "seat");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::{error::EloquentError, schema::ResolvedColumn, PerformChecks, QueryBuilder};
+
+pub struct UnknownTablesAndColumns;
+
+impl PerformChecks for UnknownTablesAndColumns {
+    fn check(builder: &QueryBuilder) -> Result<(), EloquentError> {
+        let Some(schema) = builder.active_schema() else {
+            return Ok(());
+        };
+
+        let scope = schema.scope(builder)?;
+
+        let aliases = builder
+            .selects
+            .iter()
+            .filter_map(|select| select.alias.as_ref())
+            .collect::<Vec<&String>>();
+
+        let selects = builder
+            .selects
+            .iter()
+            .filter(|select| select.expression.is_none())
+            .map(|select| &select.column);
+
+        let conditions = builder
+            .conditions
+            .iter()
+            .chain(builder.closures.iter().flat_map(|(_, closure)| closure))
+            .filter(|condition| condition.has_field())
+            .map(|condition| &condition.field);
+
+        let updates = builder.updates.iter().map(|update| &update.column);
+
+        let order_by = builder
+            .order_by
+            .iter()
+            .filter(|order| order.expression.is_none())
+            .map(|order| &order.column);
+
+        let columns = selects
+            .chain(conditions)
+            .chain(builder.insert_columns())
+            .chain(updates)
+            .chain(&builder.group_by)
+            .chain(order_by)
+            .chain(&builder.returning);
+
+        for column in columns {
+            if aliases.contains(&column) {
+                continue;
+            }
+
+            if let ResolvedColumn::Unknown = scope.resolve(column) {
+                return Err(EloquentError::UnknownColumn(column.to_string()));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::EloquentError, ColumnType, QueryBuilder, Schema, SubqueryBuilder, TableSchema,
+    };
+
+    fn schema() -> Schema {
+        Schema::new()
+            .table(
+                TableSchema::new("flights")
+                    .column("id", ColumnType::Integer)
+                    .column("flight_number", ColumnType::Text),
+            )
+            .table(
+                TableSchema::new("airports")
+                    .column("code", ColumnType::Text)
+                    .column("flight_id", ColumnType::Integer),
+            )
+    }
+
+    #[test]
+    fn test_unknown_table() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table("flights")
+            .join("gates", "gates.flight_id", "flights.id")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnknownTable(table)) => {
+                assert_eq!(table, "gates");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_column() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table("flights")
+            .select("flight_number")
+            .where_gt("duration_in_min", 120)
+            .sql();
+
+        match result {
+            Err(EloquentError::UnknownColumn(column)) => {
+                assert_eq!(column, "duration_in_min");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_unknown_qualified_column() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table_as("flights", "f")
+            .join("airports", "airports.flight_id", "f.id")
+            .select(vec!["f.flight_number", "airports.name"])
+            .sql();
+
+        match result {
+            Err(EloquentError::UnknownColumn(column)) => {
+                assert_eq!(column, "airports.name");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_known_columns() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table_as("flights", "f")
+            .join("airports", "airports.flight_id", "f.id")
+            .select(vec!["f.flight_number", "code"])
+            .select_count("f.id", "total")
+            .group_by(vec!["f.flight_number", "code"])
+            .order_by_desc("total")
+            .sql();
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_exists_without_column() {
+        let result = QueryBuilder::new()
+            .schema(schema())
+            .table("flights")
+            .where_exists(
+                SubqueryBuilder::new()
+                    .table("airports")
+                    .where_column("airports.flight_id", "flights.id"),
+            )
+            .sql();
+
+        assert!(result.is_ok());
+    }
+}
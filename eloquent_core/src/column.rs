@@ -0,0 +1,162 @@
+use crate::{Columnable, Selectable, SubqueryBuilder, ToSql};
+use std::marker::PhantomData;
+
+/// A column of a table that carries the Rust type of its values, usually generated with
+/// `#[derive(Table)]` from `eloquent_derive`.
+///
+/// Conditions on a typed column only accept values of the same type, so comparing an integer
+/// column with a string is a compile error. Joins render the fully-qualified `table.column` name.
+///
+/// ```
+/// use eloquent_core::{Column, QueryBuilder};
+///
+/// struct Flights;
+///
+/// impl Flights {
+///     const ID: Column<u64> = Column::new("flights", "id");
+///     const DURATION: Column<u32> = Column::new("flights", "duration_in_min");
+/// }
+///
+/// struct Airports;
+///
+/// impl Airports {
+///     const FLIGHT_ID: Column<u64> = Column::new("airports", "flight_id");
+/// }
+///
+/// let result = QueryBuilder::new()
+///     .table("flights")
+///     .join("airports", Flights::ID, Airports::FLIGHT_ID)
+///     .where_gt(Flights::DURATION, 120);
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "SELECT * FROM flights JOIN airports ON flights.id = airports.flight_id WHERE duration_in_min > 120"
+/// );
+/// ```
+///
+/// ```compile_fail
+/// use eloquent_core::{Column, QueryBuilder};
+///
+/// const DURATION: Column<u32> = Column::new("flights", "duration_in_min");
+///
+/// QueryBuilder::new().table("flights").where_gt(DURATION, "abc");
+/// ```
+pub struct Column<T> {
+    table: &'static str,
+    name: &'static str,
+    value_type: PhantomData<fn() -> T>,
+}
+
+impl<T> Column<T> {
+    pub const fn new(table: &'static str, name: &'static str) -> Self {
+        Self {
+            table,
+            name,
+            value_type: PhantomData,
+        }
+    }
+
+    /// The table the column belongs to.
+    pub const fn table(&self) -> &'static str {
+        self.table
+    }
+
+    /// The name of the column.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The name of the column prefixed with its table, e.g. `flights.id`.
+    pub fn qualified(&self) -> String {
+        format!("{}.{}", self.table, self.name)
+    }
+}
+
+impl<T> Clone for Column<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Column<T> {}
+
+impl<T> Columnable for Column<T> {
+    fn to_columns(&self) -> Vec<String> {
+        vec![self.name.to_string()]
+    }
+}
+
+impl<T> Selectable for Column<T> {
+    fn to_select_column(&self) -> String {
+        self.name.to_string()
+    }
+}
+
+/// A column used in a condition or join, either a string or a typed [`Column`].
+pub trait Field {
+    fn field_name(&self) -> String;
+
+    /// The name used where columns of several tables meet, such as join conditions.
+    fn qualified_field_name(&self) -> String {
+        self.field_name()
+    }
+}
+
+/// A column that can be compared with a value of type `V`.
+///
+/// Strings can be compared with any value, typed columns only with a matching [`ColumnValue`].
+pub trait Comparable<V>: Field {}
+
+/// A value that can be compared with a column holding values of type `T`.
+pub trait ColumnValue<T>: ToSql {}
+
+impl<T> Field for Column<T> {
+    fn field_name(&self) -> String {
+        self.name.to_string()
+    }
+
+    fn qualified_field_name(&self) -> String {
+        self.qualified()
+    }
+}
+
+impl<T, V> Comparable<V> for Column<T> where V: ColumnValue<T> {}
+
+macro_rules! impl_string_field {
+    ($($type:ty),*) => {
+        $(
+            impl Field for $type {
+                fn field_name(&self) -> String {
+                    self.to_string()
+                }
+            }
+
+            impl<V> Comparable<V> for $type {}
+        )*
+    };
+}
+
+impl_string_field!(&str, String, &String);
+
+macro_rules! impl_column_value {
+    ($($type:ty),*) => {
+        $(
+            impl ColumnValue<$type> for $type {}
+            impl ColumnValue<Option<$type>> for $type {}
+        )*
+    };
+}
+
+impl_column_value!(i32, i64, u32, u64, f32, f64, bool, String);
+
+impl ColumnValue<String> for &str {}
+impl ColumnValue<Option<String>> for &str {}
+impl ColumnValue<String> for &String {}
+impl ColumnValue<Option<String>> for &String {}
+impl ColumnValue<&str> for &str {}
+impl ColumnValue<Option<&str>> for &str {}
+
+#[cfg(feature = "nullable-types")]
+impl<T> ColumnValue<Option<T>> for Option<T> where Option<T>: ToSql {}
+
+impl<T> ColumnValue<T> for SubqueryBuilder {}
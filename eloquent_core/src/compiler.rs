@@ -1,83 +1,184 @@
 use crate::{
     builders::{
-        delete::DeleteBuilder, insert::InsertBuilder, select::SelectBuilder, update::UpdateBuilder,
+        aggregate::AggregateBuilder, delete::DeleteBuilder, insert::InsertBuilder,
+        select::SelectBuilder, update::UpdateBuilder,
+    },
+    compilers::{
+        conditions, ctes, group_by, havings, joins, limit, order_by, selects, set_operations,
     },
-    compilers::{conditions, group_by, havings, joins, limit, offset, order_by, selects},
     error::EloquentError,
-    Action, Condition, Logic, QueryBuilder, SqlBuilder, SubqueryBuilder, ToSql,
+    Action, Aggregate, Bindings, Condition, Generic, Logic, QueryBuilder, SqlBuilder,
+    SubqueryBuilder, Value,
 };
 
 pub fn build_statement(builder: &QueryBuilder) -> Result<String, EloquentError> {
+    let sql = compile_statement(builder, &mut Bindings::inline(builder.dialect.as_ref()))?;
+
+    log::trace!(target: "eloquent", "{}", sql);
+
+    Ok(sql)
+}
+
+pub fn build_statement_with_params(
+    builder: &QueryBuilder,
+) -> Result<(String, Vec<Value>), EloquentError> {
+    let mut bindings = Bindings::parameterized(builder.dialect.as_ref());
+
+    let sql = compile_statement(builder, &mut bindings)?;
+
+    log::trace!(target: "eloquent", "{}", sql);
+
+    Ok((sql, bindings.into_values()))
+}
+
+pub(crate) fn compile_statement(
+    builder: &QueryBuilder,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
     if builder.enable_checks {
         builder.perform_checks()?;
     }
 
     let mut sql = String::new();
-    let mut params: Vec<&Box<dyn ToSql>> = Vec::new();
 
-    sql = match builder.get_action() {
-        Action::Select => SelectBuilder::build(builder, &mut sql, &mut params)?,
-        Action::Insert => InsertBuilder::build(builder, &mut sql, &mut params)?,
-        Action::Update => UpdateBuilder::build(builder, &mut sql, &mut params)?,
-        Action::Delete => DeleteBuilder::build(builder, &mut sql, &mut params)?,
-    };
+    ctes::format(&builder.ctes, &mut sql, bindings)?;
+
+    match builder.get_action() {
+        Action::Select => match &builder.aggregate {
+            Some(aggregate) => AggregateBuilder::build(builder, aggregate, &mut sql, bindings),
+            None => SelectBuilder::build(builder, &mut sql, bindings),
+        },
+        Action::Insert => InsertBuilder::build(builder, &mut sql, bindings),
+        Action::Update => UpdateBuilder::build(builder, &mut sql, bindings),
+        Action::Delete => DeleteBuilder::build(builder, &mut sql, bindings),
+    }
+}
+
+pub fn build_count_statement(builder: &QueryBuilder) -> Result<String, EloquentError> {
+    let sql = compile_count_statement(builder, &mut Bindings::inline(builder.dialect.as_ref()))?;
 
-    let formatted_sql = sql.replace('?', "{}");
+    log::trace!(target: "eloquent", "{}", sql);
 
-    let formatted_sql = params
-        .iter()
-        .map(|p| p.as_ref().to_sql())
-        .fold(formatted_sql, |acc, val| {
-            acc.replacen("{}", &val.unwrap(), 1)
-        });
+    Ok(sql)
+}
+
+pub fn build_count_statement_with_params(
+    builder: &QueryBuilder,
+) -> Result<(String, Vec<Value>), EloquentError> {
+    let mut bindings = Bindings::parameterized(builder.dialect.as_ref());
+
+    let sql = compile_count_statement(builder, &mut bindings)?;
 
-    if formatted_sql.contains("{}") {
-        return Err(EloquentError::MissingPlaceholders);
+    log::trace!(target: "eloquent", "{}", sql);
+
+    Ok((sql, bindings.into_values()))
+}
+
+/// Compile a `SELECT COUNT(*)` of the rows the query selects, without its order and limit.
+pub(crate) fn compile_count_statement(
+    builder: &QueryBuilder,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    if builder.enable_checks {
+        builder.perform_checks()?;
     }
 
-    log::trace!(target: "eloquent", "{}", formatted_sql);
+    let mut sql = String::new();
+
+    ctes::format(&builder.ctes, &mut sql, bindings)?;
 
-    Ok(formatted_sql)
+    match builder.get_action() {
+        Action::Select => AggregateBuilder::build(builder, &Aggregate::Count, &mut sql, bindings),
+        Action::Insert => Err(EloquentError::CannotApplyClauseOnInsert(
+            "COUNT".to_string(),
+        )),
+        Action::Update => Err(EloquentError::CannotApplyClauseOnUpdate(
+            "COUNT".to_string(),
+        )),
+        Action::Delete => Err(EloquentError::CannotApplyClauseOnDelete(
+            "COUNT".to_string(),
+        )),
+    }
 }
 
 pub fn build_substatement(builder: &SubqueryBuilder) -> Result<String, EloquentError> {
-    let mut sql = String::new();
-    sql.push('(');
+    compile_substatement(builder, &mut Bindings::inline(&Generic))
+}
 
-    let mut params: Vec<&Box<dyn ToSql>> = Vec::new();
+pub fn build_substatement_with_params(
+    builder: &SubqueryBuilder,
+) -> Result<(String, Vec<Value>), EloquentError> {
+    let mut bindings = Bindings::parameterized(&Generic);
 
-    let closures: Vec<(Logic, Vec<Condition>)> = Vec::new();
+    let sql = compile_substatement(builder, &mut bindings)?;
 
-    selects::format(builder.table.as_ref().unwrap(), &builder.selects, &mut sql);
-    joins::format(&builder.joins, &mut sql);
-    conditions::format(&builder.conditions, &closures, &None, &mut sql, &mut params)?;
-    group_by::format(&builder.group_by, &mut sql);
-    havings::format(&builder.havings, &mut sql)?;
-    order_by::format(&builder.order_by, &mut sql);
-    limit::format(&builder.limit, &None, &mut sql);
-    offset::format(&builder.offset, &mut sql);
+    Ok((sql, bindings.into_values()))
+}
 
-    sql.push(')');
+pub(crate) fn compile_substatement(
+    builder: &SubqueryBuilder,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    Ok(format!("({})", compile_subselect(builder, bindings)?))
+}
 
-    let formatted_sql = sql.replace('?', "{}");
+/// Compile the subquery without the surrounding parentheses.
+pub(crate) fn compile_subselect(
+    builder: &SubqueryBuilder,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    let mut sql = String::new();
 
-    let formatted_sql = params
-        .iter()
-        .map(|p| p.as_ref().to_sql())
-        .fold(formatted_sql, |acc, val| {
-            acc.replacen("{}", &val.unwrap(), 1)
-        });
+    ctes::format(&builder.ctes, &mut sql, bindings)?;
 
-    if formatted_sql.contains("{}") {
-        return Err(EloquentError::MissingPlaceholders);
-    }
+    let closures: Vec<(Logic, Vec<Condition>)> = Vec::new();
+
+    let table = builder.table.as_ref().ok_or(EloquentError::MissingTable)?;
 
-    Ok(formatted_sql)
+    let offset = limit::offset(
+        builder.limit,
+        builder.offset,
+        !builder.set_operations.is_empty(),
+        bindings,
+    );
+
+    selects::format(
+        table,
+        builder.table_alias.as_ref(),
+        &builder.selects,
+        builder.limit,
+        offset,
+        &mut sql,
+        bindings,
+    )?;
+    joins::format(&builder.joins, &mut sql, bindings)?;
+    conditions::format(&builder.conditions, &closures, None, &mut sql, bindings)?;
+    group_by::format(&builder.group_by, &mut sql, bindings);
+    havings::format(
+        &builder.havings,
+        &builder.having_closures,
+        &mut sql,
+        bindings,
+    )?;
+    set_operations::format(&builder.set_operations, &mut sql, bindings)?;
+    order_by::format(&builder.order_by, None, &mut sql, bindings)?;
+    limit::format(
+        builder.limit,
+        offset,
+        !builder.order_by.is_empty(),
+        &mut sql,
+        bindings,
+    );
+
+    Ok(sql)
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{error::EloquentError, QueryBuilder};
+    use crate::{
+        bind, error::EloquentError, Case, Column, Cursor, Function, Keyset, QueryBuilder,
+        SqlServer, SubqueryBuilder, ToSql, Value, Window,
+    };
 
     #[test]
     fn test_missing_placeholder() {
@@ -115,4 +216,642 @@ mod tests {
             "SELECT * FROM flights WHERE origin_airport = '\'\'N ABC \'\'S'"
         );
     }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_conditions() {
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .r#where("origin_airport", "'N ABC 'S")
+            .where_in("destination_airport", vec!["FRA", "ZRH"])
+            .where_between("flight_duration", 60, 120)
+            .where_null("cancelled_at")
+            .where_closure(|q| q.where_gt("delay", 5).or_where("is_diverted", true))
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights WHERE origin_airport = $1 AND destination_airport IN ($2, $3) AND flight_duration BETWEEN $4 AND $5 AND cancelled_at IS NULL AND (delay > $6 OR is_diverted = $7)"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Text("'N ABC 'S".to_string()),
+                Value::Text("FRA".to_string()),
+                Value::Text("ZRH".to_string()),
+                Value::Int(60),
+                Value::Int(120),
+                Value::Int(5),
+                Value::Bool(true),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_insert() {
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .insert("origin_airport", "AMS")
+            .insert("flight_duration", 1.5)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "INSERT INTO flights (origin_airport, flight_duration) VALUES ($1, $2)"
+        );
+        assert_eq!(
+            params,
+            vec![Value::Text("AMS".to_string()), Value::Float(1.5)]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_update() {
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .update("gate_number", "D7")
+            .r#where("id", 42u64)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(sql, "UPDATE flights SET gate_number = $1 WHERE id = $2");
+        assert_eq!(params, vec![Value::Text("D7".to_string()), Value::UInt(42)]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_having() {
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .select_avg("flight_duration", "flight_duration_avg")
+            .group_by("origin_airport")
+            .having_gt("flight_duration_avg", 120)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin_airport, AVG(flight_duration) AS flight_duration_avg FROM flights GROUP BY origin_airport HAVING flight_duration_avg > $1"
+        );
+        assert_eq!(params, vec![Value::Int(120)]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_having_aggregates_and_closures() {
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .select_max("flight_number", "last_flight")
+            .group_by("origin_airport")
+            .having_count("id", ">", 5)
+            .or_having_closure(|query| {
+                query
+                    .having_in("last_flight", vec!["KL123", "KL456"])
+                    .having_aggregate(Function::Avg, "flight_duration", "<=", 90.5)
+            })
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin_airport, MAX(flight_number) AS last_flight FROM flights GROUP BY origin_airport HAVING COUNT(id) > $1 OR (last_flight IN ($2, $3) AND AVG(flight_duration) <= $4)"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Int(5),
+                Value::Text("KL123".to_string()),
+                Value::Text("KL456".to_string()),
+                Value::Float(90.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_having_aggregate_unsupported_operator() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .group_by("origin_airport")
+            .having_count("id", "> 5 OR 1 =", 1)
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedOperator(operator)) => {
+                assert_eq!(operator, "> 5 OR 1 =")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_having_aggregate_distinct() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .group_by("origin_airport")
+            .having_aggregate(Function::Distinct, "destination_airport", ">", 3)
+            .sql();
+
+        match result {
+            Err(EloquentError::NotAnAggregateFunction(function)) => {
+                assert_eq!(function, "DISTINCT")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_case() {
+        let label = Case::new()
+            .when(
+                |query| query.where_in("status", vec!["cancelled", "diverted"]),
+                "Disrupted",
+            )
+            .r#else("Scheduled");
+
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .select_as(label, "label")
+            .where_gt("delay_in_min", 15)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT CASE WHEN status IN ($1, $2) THEN $3 ELSE $4 END AS label FROM flights WHERE delay_in_min > $5"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Text("cancelled".to_string()),
+                Value::Text("diverted".to_string()),
+                Value::Text("Disrupted".to_string()),
+                Value::Text("Scheduled".to_string()),
+                Value::Int(15)
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_join_on() {
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .join_on("fares", |join| {
+                join.on("fares.flight_id", "=", "flights.id")
+                    .on_value("fares.class", "business")
+            })
+            .where_gt("fares.price", 500)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights JOIN fares ON fares.flight_id = flights.id AND fares.class = $1 WHERE fares.price > $2"
+        );
+        assert_eq!(
+            params,
+            vec![Value::Text("business".to_string()), Value::Int(500)]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_typed_columns() {
+        const FLIGHT_ID: Column<u64> = Column::new("flights", "id");
+        const FARE_FLIGHT_ID: Column<u64> = Column::new("fares", "flight_id");
+        const FARE_CLASS: Column<String> = Column::new("fares", "class");
+
+        let (sql, params) = QueryBuilder::new()
+            .table("flights")
+            .join_on("fares", |join| {
+                join.on(FARE_FLIGHT_ID, "=", FLIGHT_ID)
+                    .on_value(FARE_CLASS, "business")
+            })
+            .where_in(FLIGHT_ID, vec![1, 2])
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights JOIN fares ON fares.flight_id = flights.id AND fares.class = $1 WHERE id IN ($2, $3)"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Text("business".to_string()),
+                Value::UInt(1),
+                Value::UInt(2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_where_between_with_different_value_types() {
+        const DEPARTURE_DATE: Column<String> = Column::new("flights", "departure_date");
+
+        let result = QueryBuilder::new()
+            .table("flights")
+            .where_between("departure_date", "2024-01-01", String::from("2024-01-31"))
+            .where_between("flight_duration", 60i32, 180i64)
+            .or_where_between(DEPARTURE_DATE, "2024-02-01", String::from("2024-02-29"))
+            .sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "SELECT * FROM flights WHERE departure_date BETWEEN '2024-01-01' AND '2024-01-31' AND flight_duration BETWEEN 60 AND 180 OR departure_date BETWEEN '2024-02-01' AND '2024-02-29'"
+        );
+
+        let result = SubqueryBuilder::new()
+            .table("flights")
+            .where_between("flight_duration", 60u32, 180u64)
+            .to_sql();
+
+        assert_eq!(
+            result.unwrap(),
+            "(SELECT * FROM flights WHERE flight_duration BETWEEN 60 AND 180)"
+        );
+    }
+
+    #[test]
+    fn test_empty_case() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_as(Case::new().r#else("On time"), "status_label")
+            .sql();
+
+        assert_eq!(result, Err(EloquentError::MissingCaseBranches));
+    }
+
+    #[test]
+    fn test_order_by_string_columns() {
+        let column = String::from("departure_time");
+
+        let sql = QueryBuilder::new()
+            .table("flights")
+            .order_by_asc(&column)
+            .order_by_desc(column)
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM flights ORDER BY departure_time ASC, departure_time DESC"
+        );
+    }
+
+    #[test]
+    fn test_select_over_distinct() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select_over(
+                Function::Distinct,
+                "origin_airport",
+                Window::new().partition_by("destination_airport"),
+                "origins",
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::NotAnAggregateFunction(function)) => {
+                assert_eq!(function, "DISTINCT")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_join_on_without_conditions() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join_on("gates", |join| join)
+            .sql();
+
+        match result {
+            Err(EloquentError::MissingJoinConditions(table)) => assert_eq!(table, "gates"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_join_on_unsupported_operator() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .join_on("fares", |join| {
+                join.on("fares.flight_id", "= 1 OR 1 =", "flights.id")
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedOperator(operator)) => {
+                assert_eq!(operator, "= 1 OR 1 =")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_paginate() {
+        let (sql, params) = QueryBuilder::new()
+            .table("departures")
+            .paginate("id", Some(1000), 25)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM departures WHERE id > $1 ORDER BY id ASC LIMIT 25"
+        );
+        assert_eq!(params, vec![Value::Int(1000)]);
+
+        let sql = QueryBuilder::new()
+            .table("departures")
+            .r#where("status", "scheduled")
+            .paginate("id", Some(bind(2)), 25)
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM departures WHERE status = 'scheduled' AND id > $2 ORDER BY id ASC LIMIT 25"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_count_query() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .select("origin_airport")
+            .select_avg("flight_duration", "flight_duration_avg")
+            .where_gt("flight_duration", 60)
+            .group_by("origin_airport")
+            .having_gt("flight_duration_avg", 120)
+            .order_by_desc("flight_duration_avg")
+            .for_page(2, 20);
+
+        let (sql, params) = query.count_query().to_sql_with_params().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT COUNT(*) FROM (SELECT origin_airport, AVG(flight_duration) AS flight_duration_avg FROM flights WHERE flight_duration > $1 GROUP BY origin_airport HAVING flight_duration_avg > $2) AS aggregate_table"
+        );
+        assert_eq!(params, vec![Value::Int(60), Value::Int(120)]);
+
+        let (sql, _) = query.to_sql_with_params().unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT origin_airport, AVG(flight_duration) AS flight_duration_avg FROM flights WHERE flight_duration > $1 GROUP BY origin_airport HAVING flight_duration_avg > $2 ORDER BY flight_duration_avg DESC LIMIT 20 OFFSET 20"
+        );
+    }
+
+    #[test]
+    fn test_count_query_of_aggregate_select() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .select_max("flight_duration", "longest_flight")
+            .where_gt("flight_duration", 60);
+
+        assert_eq!(
+            query.count_query().sql().unwrap(),
+            "SELECT COUNT(*) FROM (SELECT MAX(flight_duration) AS longest_flight FROM flights WHERE flight_duration > 60) AS aggregate_table"
+        );
+    }
+
+    #[test]
+    fn test_for_page_out_of_range() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .order_by_asc("id")
+            .for_page(u64::MAX, 50);
+
+        match query.sql() {
+            Err(EloquentError::PageOutOfRange(page, per_page)) => {
+                assert_eq!((page, per_page), (u64::MAX, 50))
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_into_aggregate_distinct() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .into_aggregate(Function::Distinct, "origin_airport")
+            .sql();
+
+        match result {
+            Err(EloquentError::NotAnAggregateFunction(function)) => {
+                assert_eq!(function, "DISTINCT")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_into_aggregate_qualified_column_of_grouped_query() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("flights.origin_airport")
+            .select_max("flights.flight_duration", "longest_flight")
+            .join("airports", "flights.origin_airport", "airports.code")
+            .group_by("flights.origin_airport")
+            .into_aggregate(Function::Max, "flights.origin_airport");
+
+        assert_eq!(
+            result.sql().unwrap(),
+            "SELECT MAX(origin_airport) FROM (SELECT flights.origin_airport, MAX(flights.flight_duration) AS longest_flight FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport) AS aggregate_table"
+        );
+    }
+
+    #[test]
+    fn test_into_aggregate_column_not_selected_by_grouped_query() {
+        let result = QueryBuilder::new()
+            .table("flights")
+            .select("origin")
+            .select_count("id", "total")
+            .group_by("origin")
+            .into_aggregate(Function::Max, "f.price")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnknownColumn(column)) => assert_eq!(column, "f.price"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_params_exists() {
+        let (sql, params) = QueryBuilder::new()
+            .dialect(SqlServer)
+            .table("flights")
+            .select("origin_airport")
+            .where_gt("flight_duration", 120)
+            .group_by("origin_airport")
+            .having_count("id", ">=", 10)
+            .limit(5)
+            .into_exists()
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT CASE WHEN EXISTS(SELECT [origin_airport] FROM [flights] WHERE [flight_duration] > @p1 GROUP BY [origin_airport] HAVING COUNT([id]) >= @p2) THEN 1 ELSE 0 END"
+        );
+        assert_eq!(params, vec![Value::Int(120), Value::Int(10)]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_keyset_previous_page() {
+        let cursor = Cursor::previous(vec![Value::Text("2024-01-01".to_string()), Value::Int(42)]);
+
+        let (sql, params) = QueryBuilder::new()
+            .table("posts")
+            .r#where("author_id", 7)
+            .or_where("featured", true)
+            .keyset(
+                Keyset::new(10)
+                    .order_by_desc("created_at")
+                    .order_by_asc("id")
+                    .cursor(Cursor::decode(&cursor.encode()).unwrap()),
+            )
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM posts WHERE (author_id = $1 OR featured = $2) AND (created_at > $3 OR (created_at = $4 AND id < $5)) ORDER BY created_at ASC, id DESC LIMIT 10"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Int(7),
+                Value::Bool(true),
+                Value::Text("2024-01-01".to_string()),
+                Value::Text("2024-01-01".to_string()),
+                Value::Int(42)
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_nested_subqueries() {
+        let subquery = SubqueryBuilder::new()
+            .table("tickets")
+            .select("event_id")
+            .r#where("currency", "EUR");
+
+        let (sql, params) = QueryBuilder::new()
+            .table("events")
+            .select_raw("price * ? AS price_with_fee", vec![1.1])
+            .r#where("venue", "Ziggo Dome")
+            .where_in("event_id", vec![subquery])
+            .where_gt("capacity", 500)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT price * $1 AS price_with_fee FROM events WHERE venue = $2 AND event_id IN (SELECT event_id FROM tickets WHERE currency = $3) AND capacity > $4"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Float(1.1),
+                Value::Text("Ziggo Dome".to_string()),
+                Value::Text("EUR".to_string()),
+                Value::Int(500),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_exists_and_scalar_subqueries() {
+        let average = SubqueryBuilder::new()
+            .table("tickets")
+            .select_avg("price", "price_avg")
+            .where_column("tickets.event_id", "events.id")
+            .r#where("currency", "EUR");
+
+        let sold_out = SubqueryBuilder::new()
+            .table("tickets")
+            .where_column("tickets.event_id", "events.id")
+            .where_exists(
+                SubqueryBuilder::new()
+                    .table("orders")
+                    .where_column("orders.ticket_id", "tickets.id")
+                    .r#where("status", "paid"),
+            )
+            .where_lt("tickets.price", 100);
+
+        let refunded = SubqueryBuilder::new()
+            .table("refunds")
+            .where_column("refunds.event_id", "events.id");
+
+        let (sql, params) = QueryBuilder::new()
+            .table("events")
+            .r#where("venue", "Ziggo Dome")
+            .where_gt("price", average)
+            .where_exists(sold_out)
+            .where_not_exists(refunded)
+            .where_gte("capacity", 500)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM events WHERE venue = $1 AND price > (SELECT AVG(price) AS price_avg FROM tickets WHERE tickets.event_id = events.id AND currency = $2) AND EXISTS (SELECT * FROM tickets WHERE tickets.event_id = events.id AND EXISTS (SELECT * FROM orders WHERE orders.ticket_id = tickets.id AND status = $3) AND tickets.price < $4) AND NOT EXISTS (SELECT * FROM refunds WHERE refunds.event_id = events.id) AND capacity >= $5"
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Text("Ziggo Dome".to_string()),
+                Value::Text("EUR".to_string()),
+                Value::Text("paid".to_string()),
+                Value::Int(100),
+                Value::Int(500),
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "bind-placeholder-questionmark"))]
+    fn test_params_with_bind_placeholders() {
+        let query = QueryBuilder::new()
+            .table("flights")
+            .r#where("airline_id", bind(1))
+            .r#where("flight_duration", 120);
+
+        match query.to_sql_with_params() {
+            Err(EloquentError::BindInParameterizedQuery(index)) => assert_eq!(index, 1),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+
+        assert_eq!(
+            query.sql().unwrap(),
+            "SELECT * FROM flights WHERE airline_id = $1 AND flight_duration = 120"
+        );
+    }
 }
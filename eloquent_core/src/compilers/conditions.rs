@@ -1,31 +1,52 @@
-use crate::{error::EloquentError, Condition, Logic, Operator, Paginate, ToSql};
+use crate::{error::EloquentError, Bindings, Condition, Keyset, Logic};
 
-#[allow(clippy::borrowed_box)]
-pub(crate) fn format<'a>(
-    conditions: &'a [Condition],
-    closures: &'a [(Logic, Vec<Condition>)],
-    paginate: &'a Option<Paginate>,
+pub(crate) fn format(
+    conditions: &[Condition],
+    closures: &[(Logic, Vec<Condition>)],
+    keyset: Option<&Keyset>,
     sql: &mut String,
-    params: &mut Vec<&'a Box<dyn ToSql + 'static>>,
+    bindings: &mut Bindings,
 ) -> Result<String, EloquentError> {
-    if conditions.is_empty() && closures.is_empty() && paginate.is_none() {
-        return Ok(sql.to_string());
-    }
+    let mut clauses = Vec::new();
+
+    if !conditions.is_empty() || !closures.is_empty() {
+        let filters = format_conditions(conditions, closures, bindings)?;
 
-    if let Some(paginate) = paginate {
-        if let Some(last_id) = &paginate.last_id {
-            sql.push_str(&format!(
-                " WHERE {} > {}",
-                paginate.column,
-                last_id.to_sql()?
-            ));
+        let has_or = conditions
+            .iter()
+            .skip(1)
+            .any(|condition| condition.logic == Logic::Or)
+            || closures.iter().any(|(logic, _)| *logic == Logic::Or);
+
+        // the filters are grouped so an OR does not escape the keyset condition
+        match keyset.is_some_and(|keyset| keyset.has_cursor()) && has_or {
+            true => clauses.push(format!("({})", filters)),
+            false => clauses.push(filters),
         }
     }
 
-    if paginate.is_none() {
+    if let Some(condition) = keyset
+        .map(|keyset| keyset.format_condition(bindings))
+        .transpose()?
+        .flatten()
+    {
+        clauses.push(condition);
+    }
+
+    if !clauses.is_empty() {
         sql.push_str(" WHERE ");
+        sql.push_str(&clauses.join(" AND "));
     }
 
+    Ok(sql.to_string())
+}
+
+/// Format the conditions and nested closures without a leading `WHERE`.
+pub(crate) fn format_conditions(
+    conditions: &[Condition],
+    closures: &[(Logic, Vec<Condition>)],
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
     let mut conditions_str = String::new();
     let mut first_condition = true;
 
@@ -37,12 +58,9 @@ pub(crate) fn format<'a>(
             });
         }
 
-        let condition_sql = condition.format_sql();
+        let condition_sql = condition.format_sql(bindings)?;
 
         conditions_str.push_str(&condition_sql);
-        if !matches!(condition.operator, Operator::IsNull | Operator::IsNotNull) {
-            params.extend(condition.values.iter());
-        }
 
         first_condition = false;
     }
@@ -64,18 +82,13 @@ pub(crate) fn format<'a>(
                 });
             }
 
-            let condition_sql = condition.format_sql();
+            let condition_sql = condition.format_sql(bindings)?;
 
             conditions_str.push_str(&condition_sql);
-            if !matches!(condition.operator, Operator::IsNull | Operator::IsNotNull) {
-                params.extend(condition.values.iter());
-            }
         }
         conditions_str.push(')');
         first_condition = false;
     }
 
-    sql.push_str(&conditions_str);
-
-    Ok(sql.to_string())
+    Ok(conditions_str)
 }
@@ -0,0 +1,51 @@
+use crate::{compiler::compile_subselect, error::EloquentError, Bindings, Cte};
+
+pub(crate) fn format(
+    ctes: &[Cte],
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    if ctes.is_empty() {
+        return Ok(sql.to_string());
+    }
+
+    if ctes.iter().any(|cte| cte.recursive.is_some()) {
+        sql.push_str(bindings.dialect().with_recursive_keyword());
+    } else {
+        sql.push_str("WITH");
+    }
+
+    sql.push(' ');
+
+    let mut expressions = Vec::new();
+
+    for cte in ctes {
+        let mut expression = bindings.identifier(&cte.name);
+
+        if !cte.columns.is_empty() {
+            let columns = cte
+                .columns
+                .iter()
+                .map(|column| bindings.identifier(column))
+                .collect::<Vec<String>>();
+
+            expression.push_str(&format!(" ({})", columns.join(", ")));
+        }
+
+        let mut body = compile_subselect(&cte.query, bindings)?;
+
+        if let Some(recursive) = &cte.recursive {
+            body.push_str(" UNION ALL ");
+            body.push_str(&compile_subselect(recursive, bindings)?);
+        }
+
+        expression.push_str(&format!(" AS ({})", body));
+
+        expressions.push(expression);
+    }
+
+    sql.push_str(&expressions.join(", "));
+    sql.push(' ');
+
+    Ok(sql.to_string())
+}
@@ -1,6 +1,13 @@
-pub(crate) fn format(table: &str, sql: &mut String) -> String {
+use crate::Bindings;
+
+pub(crate) fn format(
+    table: &str,
+    alias: Option<&String>,
+    sql: &mut String,
+    bindings: &Bindings,
+) -> String {
     sql.push_str("DELETE FROM ");
-    sql.push_str(table);
+    sql.push_str(&bindings.table(table, alias));
 
     sql.to_string()
 }
@@ -1,7 +1,15 @@
-pub(crate) fn format(group_by: &[String], sql: &mut String) -> String {
+use crate::Bindings;
+
+pub(crate) fn format(group_by: &[String], sql: &mut String, bindings: &Bindings) -> String {
     if !group_by.is_empty() {
         sql.push_str(" GROUP BY ");
-        sql.push_str(&group_by.join(", "));
+        sql.push_str(
+            &group_by
+                .iter()
+                .map(|column| bindings.identifier(column))
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
     }
 
     sql.to_string()
@@ -1,25 +1,14 @@
-use crate::{error::EloquentError, Having};
-
-pub(crate) fn format(havings: &[Having], sql: &mut String) -> Result<String, EloquentError> {
-    if !havings.is_empty() {
+use crate::{compilers::conditions, error::EloquentError, Bindings, Condition, Logic};
+
+pub(crate) fn format(
+    havings: &[Condition],
+    closures: &[(Logic, Vec<Condition>)],
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    if !havings.is_empty() || !closures.is_empty() {
         sql.push_str(" HAVING ");
-
-        let havings = &havings;
-
-        sql.push_str(
-            &havings
-                .iter()
-                .map(|clause| {
-                    clause
-                        .conditions
-                        .iter()
-                        .map(|condition| condition.format_sql())
-                        .collect::<Vec<String>>()
-                        .join(" AND ")
-                })
-                .collect::<Vec<String>>()
-                .join(", "),
-        );
+        sql.push_str(&conditions::format_conditions(havings, closures, bindings)?);
     }
 
     Ok(sql.to_string())
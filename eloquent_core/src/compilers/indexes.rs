@@ -0,0 +1,75 @@
+use crate::{
+    compilers::conditions, error::EloquentError, Bindings, Dialect, DropIndexSyntax, Feature,
+    IndexDefinition,
+};
+
+pub(crate) fn format_create(
+    index: &IndexDefinition,
+    dialect: &dyn Dialect,
+) -> Result<String, EloquentError> {
+    let mut bindings = Bindings::inline(dialect);
+
+    let mut sql = String::from("CREATE ");
+
+    if index.unique {
+        sql.push_str("UNIQUE ");
+    }
+
+    sql.push_str("INDEX ");
+
+    if index.concurrently {
+        ensure_supported(Feature::ConcurrentIndex, dialect)?;
+
+        sql.push_str("CONCURRENTLY ");
+    }
+
+    let columns = index
+        .columns
+        .iter()
+        .map(|column| bindings.identifier(column))
+        .collect::<Vec<String>>();
+
+    sql.push_str(&format!(
+        "{} ON {} ({})",
+        bindings.identifier(&index.name),
+        bindings.identifier(&index.table),
+        columns.join(", ")
+    ));
+
+    if !index.conditions.is_empty() || !index.closures.is_empty() {
+        ensure_supported(Feature::PartialIndex, dialect)?;
+
+        sql.push_str(" WHERE ");
+        sql.push_str(&conditions::format_conditions(
+            &index.conditions,
+            &index.closures,
+            &mut bindings,
+        )?);
+    }
+
+    Ok(sql)
+}
+
+pub(crate) fn format_drop(index: &str, table: &str, dialect: &dyn Dialect) -> String {
+    let bindings = Bindings::inline(dialect);
+
+    match dialect.drop_index_syntax() {
+        DropIndexSyntax::Standalone => format!("DROP INDEX {}", bindings.identifier(index)),
+        DropIndexSyntax::OnTable => format!(
+            "DROP INDEX {} ON {}",
+            bindings.identifier(index),
+            bindings.identifier(table)
+        ),
+    }
+}
+
+fn ensure_supported(feature: Feature, dialect: &dyn Dialect) -> Result<(), EloquentError> {
+    if !dialect.supports(feature) {
+        return Err(EloquentError::UnsupportedByDialect(
+            feature.to_string(),
+            dialect.name().to_string(),
+        ));
+    }
+
+    Ok(())
+}
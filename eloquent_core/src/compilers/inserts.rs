@@ -1,19 +1,18 @@
-use crate::{Insert, ToSql};
+use crate::{compiler::compile_subselect, error::EloquentError, Bindings, Insert, InsertUsing};
 
-#[allow(clippy::borrowed_box)]
-pub(crate) fn format<'a>(
+pub(crate) fn format(
     table: &str,
-    inserts: &'a [Insert],
+    inserts: &[Insert],
     sql: &mut String,
-    params: &mut Vec<&'a Box<dyn ToSql + 'static>>,
-) -> String {
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
     sql.push_str("INSERT INTO ");
-    sql.push_str(table);
+    sql.push_str(&bindings.identifier(table));
     sql.push_str(" (");
 
     let columns: Vec<_> = inserts
         .iter()
-        .map(|insert| insert.column.as_str())
+        .map(|insert| bindings.identifier(&insert.column))
         .collect();
     sql.push_str(&columns.join(", "));
 
@@ -23,18 +22,38 @@ pub(crate) fn format<'a>(
 
     let mut value_placeholders = vec![];
     for i in 0..row_count {
-        let row_values: Vec<_> = inserts
+        let row_values = inserts
             .iter()
-            .map(|insert| {
-                params.push(&insert.values[i]);
-                "?".to_string()
-            })
-            .collect();
+            .map(|insert| insert.values[i].to_sql_bound(bindings))
+            .collect::<Result<Vec<String>, EloquentError>>()?;
 
         value_placeholders.push(format!("({})", row_values.join(", ")));
     }
 
     sql.push_str(&value_placeholders.join(", "));
 
-    sql.to_string()
+    Ok(sql.to_string())
+}
+
+pub(crate) fn format_using(
+    table: &str,
+    insert_using: &InsertUsing,
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    sql.push_str("INSERT INTO ");
+    sql.push_str(&bindings.identifier(table));
+    sql.push_str(" (");
+
+    let columns: Vec<_> = insert_using
+        .columns
+        .iter()
+        .map(|column| bindings.identifier(column))
+        .collect();
+    sql.push_str(&columns.join(", "));
+
+    sql.push_str(") ");
+    sql.push_str(&compile_subselect(&insert_using.query, bindings)?);
+
+    Ok(sql.to_string())
 }
@@ -1,18 +1,63 @@
-use crate::Join;
+use crate::{
+    compiler::compile_substatement, error::EloquentError, Bindings, Join, JoinSource, JoinType,
+    LateralSyntax,
+};
 
-pub(crate) fn format(joins: &[Join], sql: &mut String) -> String {
+pub(crate) fn format(
+    joins: &[Join],
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
     for join in joins {
+        let dialect = bindings.dialect();
+
+        if let Some(feature) = join.join_type.feature() {
+            if !dialect.supports(feature) {
+                return Err(EloquentError::UnsupportedByDialect(
+                    feature.to_string(),
+                    dialect.name().to_string(),
+                ));
+            }
+        }
+
+        if join.clause.is_empty() && join.join_type.requires_condition() {
+            return Err(EloquentError::MissingJoinConditions(match &join.source {
+                JoinSource::Table(table) => table.clone(),
+                JoinSource::Subquery(_) => join.alias.clone().unwrap_or_default(),
+            }));
+        }
+
+        let join_type = match (&join.join_type, dialect.lateral_syntax()) {
+            (JoinType::Lateral, LateralSyntax::Apply) => "CROSS APPLY".to_string(),
+            (JoinType::LeftLateral, LateralSyntax::Apply) => "OUTER APPLY".to_string(),
+            (join_type, _) => join_type.to_string(),
+        };
+
         sql.push(' ');
 
-        sql.push_str(&join.join_type.to_string());
+        sql.push_str(&join_type);
 
         sql.push(' ');
-        sql.push_str(&join.table);
-        sql.push_str(" ON ");
-        sql.push_str(&join.left_hand);
-        sql.push_str(" = ");
-        sql.push_str(&join.right_hand);
+        sql.push_str(&match &join.source {
+            JoinSource::Table(table) => bindings.table(table, join.alias.as_ref()),
+            JoinSource::Subquery(query) => format!(
+                "{} AS {}",
+                compile_substatement(query, bindings)?,
+                bindings.identifier(join.alias.as_deref().unwrap_or_default())
+            ),
+        });
+
+        if !join.clause.is_empty() {
+            sql.push_str(" ON ");
+            sql.push_str(&join.clause.format(bindings)?);
+        } else if matches!(join.join_type, JoinType::LeftLateral)
+            && dialect.lateral_syntax() == LateralSyntax::Lateral
+        {
+            // a left join needs a condition, the lateral subquery already filters its rows
+            sql.push_str(" ON ");
+            sql.push_str(&dialect.boolean_literal(true));
+        }
     }
 
-    sql.to_string()
+    Ok(sql.to_string())
 }
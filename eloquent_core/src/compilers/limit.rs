@@ -1,11 +1,28 @@
-use crate::Paginate;
+use crate::Bindings;
 
-pub(crate) fn format(limit: &Option<u64>, paginate: &Option<Paginate>, sql: &mut String) -> String {
-    if let Some(paginate) = paginate {
-        sql.push_str(&format!(" LIMIT {}", paginate.per_page));
-    } else if let Some(limit) = &limit {
-        sql.push_str(&format!(" LIMIT {}", limit));
-    }
+pub(crate) fn format(
+    limit: Option<u64>,
+    offset: Option<u64>,
+    ordered: bool,
+    sql: &mut String,
+    bindings: &Bindings,
+) -> String {
+    sql.push_str(&bindings.dialect().limit_offset(limit, offset, ordered));
 
     sql.to_string()
 }
+
+/// The offset to compile, a set operation forces an offset so the limit is not rendered after SELECT.
+pub(crate) fn offset(
+    limit: Option<u64>,
+    offset: Option<u64>,
+    combined: bool,
+    bindings: &Bindings,
+) -> Option<u64> {
+    // a limit rendered after SELECT would only apply to the first branch of a set operation
+    if combined && bindings.dialect().select_prefix(limit, offset).is_some() {
+        return Some(0);
+    }
+
+    offset
+}
@@ -1,11 +1,16 @@
 pub mod conditions;
+pub mod ctes;
 pub mod delete;
 pub mod group_by;
 pub mod havings;
+pub mod indexes;
 pub mod inserts;
 pub mod joins;
 pub mod limit;
-pub mod offset;
 pub mod order_by;
+pub mod returning;
 pub mod selects;
+pub mod set_operations;
+pub mod tables;
 pub mod updates;
+pub mod upserts;
@@ -1,18 +1,28 @@
-use crate::OrderColumn;
+use crate::{error::EloquentError, Bindings, Keyset, OrderColumn};
 
-pub(crate) fn format(order_by: &[OrderColumn], sql: &mut String) -> String {
-    if !order_by.is_empty() {
-        sql.push_str(" ORDER BY ");
-        order_by.iter().for_each(|order| {
-            sql.push_str(&order.column);
-            sql.push(' ');
-            sql.push_str(&order.order.to_string());
+pub(crate) fn format(
+    order_by: &[OrderColumn],
+    keyset: Option<&Keyset>,
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    let mut columns = keyset
+        .map(|keyset| keyset.format_order_by(bindings))
+        .unwrap_or_default();
+
+    for order in order_by {
+        let column = match &order.expression {
+            Some(expression) => expression.to_sql_bound(bindings)?,
+            None => bindings.identifier(&order.column),
+        };
 
-            if order != order_by.last().unwrap() {
-                sql.push_str(", ");
-            }
-        });
+        columns.push(format!("{} {}", column, order.order));
+    }
+
+    if !columns.is_empty() {
+        sql.push_str(" ORDER BY ");
+        sql.push_str(&columns.join(", "));
     }
 
-    sql.to_string()
+    Ok(sql.to_string())
 }
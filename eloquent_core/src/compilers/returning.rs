@@ -0,0 +1,29 @@
+use crate::{error::EloquentError, Bindings, Feature};
+
+pub(crate) fn format(
+    returning: &[String],
+    sql: &mut String,
+    bindings: &Bindings,
+) -> Result<String, EloquentError> {
+    if returning.is_empty() {
+        return Ok(sql.to_string());
+    }
+
+    let dialect = bindings.dialect();
+
+    if !dialect.supports(Feature::Returning) {
+        return Err(EloquentError::UnsupportedReturning(
+            dialect.name().to_string(),
+        ));
+    }
+
+    let columns = returning
+        .iter()
+        .map(|column| bindings.identifier(column))
+        .collect::<Vec<String>>();
+
+    sql.push_str(" RETURNING ");
+    sql.push_str(&columns.join(", "));
+
+    Ok(sql.to_string())
+}
@@ -1,22 +1,35 @@
-use crate::Select;
+use crate::{error::EloquentError, Bindings, Select};
 
-pub(crate) fn format(table: &str, selects: &[Select], sql: &mut String) -> String {
+pub(crate) fn format(
+    table: &str,
+    alias: Option<&String>,
+    selects: &[Select],
+    limit: Option<u64>,
+    offset: Option<u64>,
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
     sql.push_str("SELECT ");
 
+    if let Some(prefix) = bindings.dialect().select_prefix(limit, offset) {
+        sql.push_str(&prefix);
+        sql.push(' ');
+    }
+
     if selects.is_empty() {
         sql.push('*');
     } else {
         sql.push_str(
             &selects
                 .iter()
-                .map(|s| s.format_column_name())
-                .collect::<Vec<String>>()
+                .map(|s| s.format_column_name(bindings))
+                .collect::<Result<Vec<String>, EloquentError>>()?
                 .join(", "),
         );
     }
 
     sql.push_str(" FROM ");
-    sql.push_str(table);
+    sql.push_str(&bindings.table(table, alias));
 
-    sql.to_string()
+    Ok(sql.to_string())
 }
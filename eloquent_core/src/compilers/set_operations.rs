@@ -0,0 +1,16 @@
+use crate::{error::EloquentError, Bindings, SetOperation};
+
+pub(crate) fn format(
+    set_operations: &[SetOperation],
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    for set_operation in set_operations {
+        sql.push(' ');
+        sql.push_str(&set_operation.operator.to_string());
+        sql.push(' ');
+        sql.push_str(&set_operation.query.to_combined_sql(bindings)?);
+    }
+
+    Ok(sql.to_string())
+}
@@ -0,0 +1,216 @@
+use crate::{
+    compilers::conditions,
+    error::EloquentError,
+    table_definition::{AlterOperation, ForeignKey, TableConstraint},
+    AlterTable, Bindings, ColumnDefinition, Dialect, Feature, TableDefinition,
+};
+
+pub(crate) fn format_create(
+    definition: &TableDefinition,
+    dialect: &dyn Dialect,
+) -> Result<String, EloquentError> {
+    let mut bindings = Bindings::inline(dialect);
+
+    let mut sql = String::from("CREATE TABLE ");
+
+    if definition.if_not_exists {
+        if !dialect.supports(Feature::IfNotExists) {
+            return Err(EloquentError::UnsupportedByDialect(
+                Feature::IfNotExists.to_string(),
+                dialect.name().to_string(),
+            ));
+        }
+
+        sql.push_str("IF NOT EXISTS ");
+    }
+
+    let mut elements = vec![];
+
+    for column in &definition.columns {
+        elements.push(format_column(column, &mut bindings)?);
+    }
+
+    if !definition.primary_key.is_empty() {
+        elements.push(format!(
+            "PRIMARY KEY ({})",
+            format_columns(&definition.primary_key, &bindings)
+        ));
+    }
+
+    for columns in &definition.unique {
+        elements.push(format!("UNIQUE ({})", format_columns(columns, &bindings)));
+    }
+
+    for foreign_key in &definition.foreign_keys {
+        elements.push(format_foreign_key(foreign_key, &bindings));
+    }
+
+    sql.push_str(&format!(
+        "{} ({})",
+        bindings.identifier(&definition.name),
+        elements.join(", ")
+    ));
+
+    Ok(sql)
+}
+
+pub(crate) fn format_alter(
+    alter_table: &AlterTable,
+    dialect: &dyn Dialect,
+) -> Result<Vec<String>, EloquentError> {
+    let mut bindings = Bindings::inline(dialect);
+
+    let table = bindings.identifier(&alter_table.name);
+
+    let mut statements = vec![];
+
+    for operation in &alter_table.operations {
+        let statement = match operation {
+            AlterOperation::Add(column) => format!(
+                "ALTER TABLE {} {} {}",
+                table,
+                dialect.add_column_keyword(),
+                format_column(column, &mut bindings)?
+            ),
+            AlterOperation::Drop(column) => format!(
+                "ALTER TABLE {} DROP COLUMN {}",
+                table,
+                bindings.identifier(column)
+            ),
+            AlterOperation::Rename(from, to) => {
+                if !dialect.supports(Feature::RenameColumn) {
+                    return Err(EloquentError::UnsupportedByDialect(
+                        Feature::RenameColumn.to_string(),
+                        dialect.name().to_string(),
+                    ));
+                }
+
+                format!(
+                    "ALTER TABLE {} RENAME COLUMN {} TO {}",
+                    table,
+                    bindings.identifier(from),
+                    bindings.identifier(to)
+                )
+            }
+            AlterOperation::AddConstraint(name, constraint) => {
+                ensure_alter_constraint(dialect)?;
+
+                format!(
+                    "ALTER TABLE {} ADD CONSTRAINT {} {}",
+                    table,
+                    bindings.identifier(name),
+                    format_constraint(constraint, &mut bindings)?
+                )
+            }
+            AlterOperation::DropConstraint(name) => {
+                ensure_alter_constraint(dialect)?;
+
+                format!(
+                    "ALTER TABLE {} DROP CONSTRAINT {}",
+                    table,
+                    bindings.identifier(name)
+                )
+            }
+        };
+
+        statements.push(statement);
+    }
+
+    Ok(statements)
+}
+
+pub(crate) fn format_drop(table: &str, if_exists: bool, dialect: &dyn Dialect) -> String {
+    let bindings = Bindings::inline(dialect);
+
+    match if_exists {
+        true => format!("DROP TABLE IF EXISTS {}", bindings.identifier(table)),
+        false => format!("DROP TABLE {}", bindings.identifier(table)),
+    }
+}
+
+fn format_column(
+    column: &ColumnDefinition,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    let mut sql = format!(
+        "{} {}",
+        bindings.identifier(&column.name),
+        bindings.dialect().column_type(column.column_type)
+    );
+
+    if !column.nullable {
+        sql.push_str(" NOT NULL");
+    }
+
+    if let Some(default) = &column.default {
+        sql.push_str(" DEFAULT ");
+        sql.push_str(&default.to_sql_bound(bindings)?);
+    }
+
+    if column.primary_key {
+        sql.push_str(" PRIMARY KEY");
+    }
+
+    if column.unique {
+        sql.push_str(" UNIQUE");
+    }
+
+    if let Some((table, reference)) = &column.references {
+        sql.push_str(&format!(
+            " REFERENCES {} ({})",
+            bindings.identifier(table),
+            bindings.identifier(reference)
+        ));
+    }
+
+    Ok(sql)
+}
+
+fn format_constraint(
+    constraint: &TableConstraint,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    let sql = match constraint {
+        TableConstraint::PrimaryKey(columns) => {
+            format!("PRIMARY KEY ({})", format_columns(columns, bindings))
+        }
+        TableConstraint::Unique(columns) => {
+            format!("UNIQUE ({})", format_columns(columns, bindings))
+        }
+        TableConstraint::ForeignKey(foreign_key) => format_foreign_key(foreign_key, bindings),
+        TableConstraint::Check(checks, closures) => format!(
+            "CHECK ({})",
+            conditions::format_conditions(checks, closures, bindings)?
+        ),
+    };
+
+    Ok(sql)
+}
+
+fn ensure_alter_constraint(dialect: &dyn Dialect) -> Result<(), EloquentError> {
+    if !dialect.supports(Feature::AlterConstraint) {
+        return Err(EloquentError::UnsupportedByDialect(
+            Feature::AlterConstraint.to_string(),
+            dialect.name().to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn format_foreign_key(foreign_key: &ForeignKey, bindings: &Bindings) -> String {
+    format!(
+        "FOREIGN KEY ({}) REFERENCES {} ({})",
+        format_columns(&foreign_key.columns, bindings),
+        bindings.identifier(&foreign_key.table),
+        format_columns(&foreign_key.references, bindings)
+    )
+}
+
+fn format_columns(columns: &[String], bindings: &Bindings) -> String {
+    columns
+        .iter()
+        .map(|column| bindings.identifier(column))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
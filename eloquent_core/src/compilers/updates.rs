@@ -1,26 +1,29 @@
-use crate::{ToSql, Update};
+use crate::{error::EloquentError, Bindings, Update};
 
-#[allow(clippy::borrowed_box)]
-pub(crate) fn format<'a>(
+pub(crate) fn format(
     table: &str,
-    updates: &'a [Update],
+    alias: Option<&String>,
+    updates: &[Update],
     sql: &mut String,
-    params: &mut Vec<&'a Box<dyn ToSql + 'static>>,
-) -> String {
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
     sql.push_str("UPDATE ");
-    sql.push_str(table);
+    sql.push_str(&bindings.table(table, alias));
     sql.push_str(" SET ");
 
     sql.push_str(
         &updates
             .iter()
             .map(|update| {
-                params.push(&update.value);
-                format!("{} = ?", update.column)
+                Ok(format!(
+                    "{} = {}",
+                    bindings.identifier(&update.column),
+                    update.value.to_sql_bound(bindings)?
+                ))
             })
-            .collect::<Vec<String>>()
+            .collect::<Result<Vec<String>, EloquentError>>()?
             .join(", "),
     );
 
-    sql.to_string()
+    Ok(sql.to_string())
 }
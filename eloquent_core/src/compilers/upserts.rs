@@ -0,0 +1,102 @@
+use crate::{compilers::conditions, error::EloquentError, Bindings, Feature, Upsert, UpsertSyntax};
+
+pub(crate) fn format(
+    upsert: &Option<Upsert>,
+    columns: &[&String],
+    sql: &mut String,
+    bindings: &mut Bindings,
+) -> Result<String, EloquentError> {
+    let Some(upsert) = upsert else {
+        return Ok(sql.to_string());
+    };
+
+    let dialect = bindings.dialect();
+
+    if !dialect.supports(Feature::Upsert) {
+        return Err(EloquentError::UnsupportedByDialect(
+            Feature::Upsert.to_string(),
+            dialect.name().to_string(),
+        ));
+    }
+
+    let has_conditions = !upsert.conditions.is_empty() || !upsert.closures.is_empty();
+
+    if has_conditions && !dialect.supports(Feature::UpsertWhere) {
+        return Err(EloquentError::UnsupportedByDialect(
+            Feature::UpsertWhere.to_string(),
+            dialect.name().to_string(),
+        ));
+    }
+
+    match dialect.upsert_syntax() {
+        UpsertSyntax::OnConflict => {
+            sql.push_str(" ON CONFLICT");
+
+            if !upsert.target.is_empty() {
+                let target = upsert
+                    .target
+                    .iter()
+                    .map(|column| bindings.identifier(column))
+                    .collect::<Vec<String>>();
+
+                sql.push_str(&format!(" ({})", target.join(", ")));
+            }
+
+            if upsert.updates.is_empty() {
+                sql.push_str(" DO NOTHING");
+
+                return Ok(sql.to_string());
+            }
+
+            // DO UPDATE needs to know which conflict it resolves
+            if upsert.target.is_empty() {
+                return Err(EloquentError::MissingConflictTarget);
+            }
+
+            let updates = upsert
+                .updates
+                .iter()
+                .map(|column| {
+                    let column = bindings.identifier(column);
+
+                    format!("{} = EXCLUDED.{}", column, column)
+                })
+                .collect::<Vec<String>>();
+
+            sql.push_str(" DO UPDATE SET ");
+            sql.push_str(&updates.join(", "));
+
+            conditions::format(&upsert.conditions, &upsert.closures, None, sql, bindings)?;
+        }
+        UpsertSyntax::OnDuplicateKeyUpdate => {
+            // without columns to update, assigning a column to itself leaves the row untouched
+            let updates = match upsert.updates.is_empty() {
+                true => upsert
+                    .target
+                    .first()
+                    .or(columns.first().copied())
+                    .map(|column| {
+                        let column = bindings.identifier(column);
+
+                        format!("{} = {}", column, column)
+                    })
+                    .into_iter()
+                    .collect::<Vec<String>>(),
+                false => upsert
+                    .updates
+                    .iter()
+                    .map(|column| {
+                        let column = bindings.identifier(column);
+
+                        format!("{} = VALUES({})", column, column)
+                    })
+                    .collect::<Vec<String>>(),
+            };
+
+            sql.push_str(" ON DUPLICATE KEY UPDATE ");
+            sql.push_str(&updates.join(", "));
+        }
+    }
+
+    Ok(sql.to_string())
+}
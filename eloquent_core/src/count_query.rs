@@ -0,0 +1,67 @@
+use crate::{
+    compiler::{build_count_statement, build_count_statement_with_params},
+    error::EloquentError,
+    Executor, QueryBuilder, Value,
+};
+
+/// A `SELECT COUNT(*)` of the rows a query selects, created with [`QueryBuilder::count_query`].
+///
+/// The count uses the tables, joins and conditions of the query, but not its order, limit or
+/// offset, so the total always matches the pages of [`QueryBuilder::for_page`].
+pub struct CountQuery<'a> {
+    builder: &'a QueryBuilder,
+}
+
+impl<'a> CountQuery<'a> {
+    pub(crate) fn new(builder: &'a QueryBuilder) -> Self {
+        Self { builder }
+    }
+
+    /// Compile the count query to a SQL string.
+    pub fn sql(&self) -> Result<String, EloquentError> {
+        build_count_statement(self.builder)
+    }
+
+    /// Compile the count query to a SQL string with positional placeholders and the ordered list of bound values.
+    pub fn to_sql_with_params(&self) -> Result<(String, Vec<Value>), EloquentError> {
+        build_count_statement_with_params(self.builder)
+    }
+
+    /// Execute the count query on an executor and return the number of rows.
+    pub fn fetch(&self, executor: &mut impl Executor) -> Result<u64, EloquentError> {
+        let (sql, params) = self.to_sql_with_params()?;
+
+        let rows = executor.query(&sql, &params)?;
+
+        match rows.first().and_then(|row| row.first()) {
+            Some(Value::Int(count)) => u64::try_from(*count)
+                .map_err(|error| EloquentError::ExecutionFailed(error.to_string())),
+            Some(Value::UInt(count)) => Ok(*count),
+            Some(value) => Err(EloquentError::ExecutionFailed(format!(
+                "COUNT(*) returned {:?}",
+                value
+            ))),
+            None => Err(EloquentError::RowNotFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, RecordingExecutor, Value};
+
+    #[test]
+    fn test_fetch_count() {
+        let query = QueryBuilder::new().table("flights").for_page(2, 20);
+
+        let mut executor = RecordingExecutor::new().rows(vec![vec![Value::Int(42)]]);
+
+        assert_eq!(query.count_query().fetch(&mut executor), Ok(42));
+        assert_eq!(executor.sql(), vec!["SELECT COUNT(*) FROM flights"]);
+
+        assert_eq!(
+            query.count_query().fetch(&mut executor),
+            Err(EloquentError::RowNotFound)
+        );
+    }
+}
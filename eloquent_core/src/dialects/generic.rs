@@ -0,0 +1,21 @@
+use super::Dialect;
+
+/// The default dialect, which leaves identifiers unquoted.
+///
+/// You can use the feature `bind-placeholder-questionmark` to control the use of `$` vs `?` for placeholders.
+#[derive(Debug, Clone, Copy)]
+pub struct Generic;
+
+impl Dialect for Generic {
+    fn name(&self) -> &'static str {
+        "Generic"
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        if cfg!(feature = "bind-placeholder-questionmark") {
+            format!("?{}", index)
+        } else {
+            format!("${}", index)
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use crate::ColumnType;
+
+mod generic;
+mod mysql;
+mod postgres;
+mod sql_server;
+mod sqlite;
+
+pub use generic::Generic;
+pub use mysql::MySql;
+pub use postgres::Postgres;
+pub use sql_server::SqlServer;
+pub use sqlite::Sqlite;
+
+/// The flavour of SQL a query is compiled to.
+///
+/// Every method has a default that matches the [`Generic`] dialect, so a dialect only has to
+/// override the parts where it differs.
+pub trait Dialect {
+    /// The name of the dialect, used in error messages.
+    fn name(&self) -> &'static str;
+
+    /// The placeholder for the bound value at the given 1-based position.
+    fn placeholder(&self, index: usize) -> String;
+
+    /// Quote a single identifier, e.g. a table, column or alias name.
+    fn quote_identifier(&self, identifier: &str) -> String {
+        identifier.to_string()
+    }
+
+    /// Render a string literal.
+    fn string_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\'', "''"))
+    }
+
+    /// Render a boolean literal.
+    fn boolean_literal(&self, value: bool) -> String {
+        value.to_string()
+    }
+
+    /// The keyword that starts a list of common table expressions containing a recursive one.
+    fn with_recursive_keyword(&self) -> &'static str {
+        "WITH RECURSIVE"
+    }
+
+    /// The operator used for `!=` comparisons.
+    fn not_equal_operator(&self) -> &'static str {
+        "!="
+    }
+
+    /// Extract a date part from a column.
+    fn date_function(&self, part: DatePart, column: &str) -> String {
+        format!("{}({})", part, column)
+    }
+
+    /// Select whether a subquery returns any rows.
+    fn exists_expression(&self, subquery: &str) -> String {
+        format!("EXISTS({})", subquery)
+    }
+
+    /// The keyword placed directly after `SELECT`, e.g. `TOP 10`.
+    fn select_prefix(&self, _limit: Option<u64>, _offset: Option<u64>) -> Option<String> {
+        None
+    }
+
+    /// The clause appended to the end of the query to limit the rows returned.
+    fn limit_offset(&self, limit: Option<u64>, offset: Option<u64>, _ordered: bool) -> String {
+        let mut sql = String::new();
+
+        if let Some(limit) = limit {
+            sql.push_str(&format!(" LIMIT {}", limit));
+        }
+
+        if let Some(offset) = offset {
+            sql.push_str(&format!(" OFFSET {}", offset));
+        }
+
+        sql
+    }
+
+    /// The syntax used to resolve conflicts of an `INSERT`.
+    fn upsert_syntax(&self) -> UpsertSyntax {
+        UpsertSyntax::OnConflict
+    }
+
+    /// The syntax used to join a lateral subquery.
+    fn lateral_syntax(&self) -> LateralSyntax {
+        LateralSyntax::Lateral
+    }
+
+    /// The type of a column in a `CREATE TABLE` or `ALTER TABLE` statement.
+    fn column_type(&self, column_type: ColumnType) -> String {
+        column_type.to_string()
+    }
+
+    /// The keywords that add a column in an `ALTER TABLE` statement.
+    fn add_column_keyword(&self) -> &'static str {
+        "ADD COLUMN"
+    }
+
+    /// The syntax used to drop an index.
+    fn drop_index_syntax(&self) -> DropIndexSyntax {
+        DropIndexSyntax::Standalone
+    }
+
+    /// Whether the dialect supports the given feature.
+    fn supports(&self, _feature: Feature) -> bool {
+        true
+    }
+
+    /// The statement that starts a transaction.
+    fn begin_transaction(&self) -> &'static str {
+        "BEGIN"
+    }
+
+    /// The statement that commits a transaction.
+    fn commit_transaction(&self) -> &'static str {
+        "COMMIT"
+    }
+
+    /// The statement that rolls back a transaction.
+    fn rollback_transaction(&self) -> &'static str {
+        "ROLLBACK"
+    }
+
+    /// The condition that only runs a `CREATE TABLE` when the table does not exist, for dialects
+    /// without `IF NOT EXISTS`.
+    fn table_missing_guard(&self, _table: &str) -> Option<String> {
+        None
+    }
+}
+
+/// The part of a date that is compared in `where_date`, `where_year`, etc.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DatePart {
+    Date,
+    Year,
+    Month,
+    Day,
+}
+
+/// SQL features that are not available in every dialect.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Feature {
+    FullJoin,
+    Upsert,
+    UpsertWhere,
+    Returning,
+    NaturalJoin,
+    Lateral,
+    IfNotExists,
+    RenameColumn,
+    ConcurrentIndex,
+    PartialIndex,
+    AlterConstraint,
+    TransactionalDdl,
+}
+
+/// The syntax of a `DROP INDEX` statement, see [`Dialect::drop_index_syntax`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DropIndexSyntax {
+    /// `DROP INDEX name`.
+    Standalone,
+    /// `DROP INDEX name ON table`.
+    OnTable,
+}
+
+/// The syntax of a lateral join, see [`Dialect::lateral_syntax`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LateralSyntax {
+    /// `CROSS JOIN LATERAL (...)` and `LEFT JOIN LATERAL (...) ON true`.
+    Lateral,
+    /// `CROSS APPLY (...)` and `OUTER APPLY (...)`.
+    Apply,
+}
+
+/// The syntax of an upsert, see [`Dialect::upsert_syntax`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UpsertSyntax {
+    /// `ON CONFLICT (...) DO NOTHING` and `ON CONFLICT (...) DO UPDATE SET col = EXCLUDED.col`.
+    OnConflict,
+    /// `ON DUPLICATE KEY UPDATE col = VALUES(col)`.
+    OnDuplicateKeyUpdate,
+}
+
+impl std::fmt::Display for DatePart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let part = match self {
+            DatePart::Date => "DATE",
+            DatePart::Year => "YEAR",
+            DatePart::Month => "MONTH",
+            DatePart::Day => "DAY",
+        };
+
+        write!(f, "{}", part)
+    }
+}
+
+impl std::fmt::Display for Feature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let feature = match self {
+            Feature::FullJoin => "FULL JOIN",
+            Feature::Upsert => "ON CONFLICT",
+            Feature::UpsertWhere => "ON CONFLICT DO UPDATE WHERE",
+            Feature::Returning => "RETURNING",
+            Feature::NaturalJoin => "NATURAL JOIN",
+            Feature::Lateral => "LATERAL",
+            Feature::IfNotExists => "IF NOT EXISTS",
+            Feature::RenameColumn => "RENAME COLUMN",
+            Feature::ConcurrentIndex => "CREATE INDEX CONCURRENTLY",
+            Feature::PartialIndex => "CREATE INDEX ... WHERE",
+            Feature::AlterConstraint => "ALTER TABLE ... CONSTRAINT",
+            Feature::TransactionalDdl => "transactional DDL",
+        };
+
+        write!(f, "{}", feature)
+    }
+}
@@ -0,0 +1,195 @@
+use super::{Dialect, DropIndexSyntax, Feature, UpsertSyntax};
+use crate::ColumnType;
+
+/// MySQL dialect.
+#[derive(Debug, Clone, Copy)]
+pub struct MySql;
+
+impl Dialect for MySql {
+    fn name(&self) -> &'static str {
+        "MySQL"
+    }
+
+    fn placeholder(&self, _index: usize) -> String {
+        "?".to_string()
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        format!("`{}`", identifier)
+    }
+
+    fn string_literal(&self, value: &str) -> String {
+        format!("'{}'", value.replace('\\', "\\\\").replace('\'', "''"))
+    }
+
+    fn limit_offset(&self, limit: Option<u64>, offset: Option<u64>, _ordered: bool) -> String {
+        match (limit, offset) {
+            // MySQL has no OFFSET without LIMIT, the documented workaround is the maximum row count
+            (None, Some(offset)) => format!(" LIMIT {} OFFSET {}", u64::MAX, offset),
+            (Some(limit), Some(offset)) => format!(" LIMIT {} OFFSET {}", limit, offset),
+            (Some(limit), None) => format!(" LIMIT {}", limit),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn upsert_syntax(&self) -> UpsertSyntax {
+        UpsertSyntax::OnDuplicateKeyUpdate
+    }
+
+    fn column_type(&self, column_type: ColumnType) -> String {
+        match column_type {
+            ColumnType::Integer => "INT".to_string(),
+            ColumnType::Float => "DOUBLE".to_string(),
+            ColumnType::Timestamp => "DATETIME".to_string(),
+            _ => column_type.to_string(),
+        }
+    }
+
+    fn drop_index_syntax(&self) -> DropIndexSyntax {
+        DropIndexSyntax::OnTable
+    }
+
+    fn supports(&self, feature: Feature) -> bool {
+        !matches!(
+            feature,
+            Feature::FullJoin
+                | Feature::UpsertWhere
+                | Feature::Returning
+                | Feature::ConcurrentIndex
+                | Feature::PartialIndex
+                | Feature::TransactionalDdl
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::EloquentError, ColumnDefinition, ColumnType, MySql, QueryBuilder, SchemaBuilder,
+    };
+
+    #[test]
+    fn test_mysql_select() {
+        let (sql, _) = QueryBuilder::new()
+            .dialect(MySql)
+            .table("flights")
+            .r#where("origin", "AMS")
+            .offset(20)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM `flights` WHERE `origin` = ? LIMIT 18446744073709551615 OFFSET 20"
+        );
+    }
+
+    #[test]
+    fn test_mysql_escapes_backslashes() {
+        let sql = QueryBuilder::new()
+            .dialect(MySql)
+            .table("flights")
+            .r#where("origin", "AMS\\'")
+            .sql()
+            .unwrap();
+
+        assert_eq!(sql, "SELECT * FROM `flights` WHERE `origin` = 'AMS\\\\'''");
+    }
+
+    #[test]
+    fn test_mysql_full_join_is_unsupported() {
+        let result = QueryBuilder::new()
+            .dialect(MySql)
+            .table("flights")
+            .full_join("airports", "flights.origin", "airports.code")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, dialect)) => {
+                assert_eq!(feature, "FULL JOIN");
+                assert_eq!(dialect, "MySQL");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_mysql_upsert_where_is_unsupported() {
+        let result = QueryBuilder::new()
+            .dialect(MySql)
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .insert("status", "delayed")
+            .do_update("status")
+            .do_update_where(|query| query.where_not("status", "cancelled"))
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, _)) => {
+                assert_eq!(feature, "ON CONFLICT DO UPDATE WHERE")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_mysql_returning_is_unsupported() {
+        let result = QueryBuilder::new()
+            .dialect(MySql)
+            .table("flights")
+            .update("status", "delayed")
+            .returning("id")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedReturning(dialect)) => assert_eq!(dialect, "MySQL"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_mysql_create_table() {
+        let sql = SchemaBuilder::new()
+            .dialect(MySql)
+            .create_table("flights", |table| {
+                table
+                    .column(ColumnDefinition::new("id", ColumnType::Integer).primary_key())
+                    .column(ColumnDefinition::new(
+                        "departure_time",
+                        ColumnType::Timestamp,
+                    ))
+                    .column(ColumnDefinition::new("price", ColumnType::Float).nullable())
+            })
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "CREATE TABLE `flights` (`id` INT NOT NULL PRIMARY KEY, `departure_time` DATETIME NOT NULL, `price` DOUBLE)"
+        );
+    }
+
+    #[test]
+    fn test_mysql_partial_index_is_unsupported() {
+        let result = SchemaBuilder::new()
+            .dialect(MySql)
+            .create_index("flights_origin_index", "flights", |index| {
+                index
+                    .columns("origin")
+                    .partial(|query| query.where_null("cancelled_at"))
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, dialect)) => {
+                assert_eq!(feature, "CREATE INDEX ... WHERE");
+                assert_eq!(dialect, "MySQL");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use super::{DatePart, Dialect};
+use crate::ColumnType;
+
+/// PostgreSQL dialect.
+#[derive(Debug, Clone, Copy)]
+pub struct Postgres;
+
+impl Dialect for Postgres {
+    fn name(&self) -> &'static str {
+        "PostgreSQL"
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("${}", index)
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        format!("\"{}\"", identifier)
+    }
+
+    fn date_function(&self, part: DatePart, column: &str) -> String {
+        match part {
+            DatePart::Date => format!("CAST({} AS DATE)", column),
+            _ => format!("EXTRACT({} FROM {})", part, column),
+        }
+    }
+
+    fn column_type(&self, column_type: ColumnType) -> String {
+        match column_type {
+            ColumnType::Float => "DOUBLE PRECISION".to_string(),
+            _ => column_type.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, Postgres, QueryBuilder, Value};
+
+    #[test]
+    fn test_postgres_select() {
+        let (sql, params) = QueryBuilder::new()
+            .dialect(Postgres)
+            .table("flights")
+            .select(vec!["flights.origin", "destination"])
+            .where_year("departure_date", 2024)
+            .where_not("status", "cancelled")
+            .limit(10)
+            .offset(20)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            r#"SELECT "flights"."origin", "destination" FROM "flights" WHERE EXTRACT(YEAR FROM "departure_date") = $1 AND "status" != $2 LIMIT 10 OFFSET 20"#
+        );
+        assert_eq!(
+            params,
+            vec![Value::Int(2024), Value::Text("cancelled".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_postgres_upsert() {
+        let (sql, params) = QueryBuilder::new()
+            .dialect(Postgres)
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .insert("status", "delayed")
+            .on_conflict("flight_number")
+            .do_update("status")
+            .do_update_where(|query| query.where_not("flights.status", "cancelled"))
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            r#"INSERT INTO "flights" ("flight_number", "status") VALUES ($1, $2) ON CONFLICT ("flight_number") DO UPDATE SET "status" = EXCLUDED."status" WHERE "flights"."status" != $3"#
+        );
+        assert_eq!(
+            params,
+            vec![
+                Value::Text("KL123".to_string()),
+                Value::Text("delayed".to_string()),
+                Value::Text("cancelled".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_postgres_upsert_where_closure() {
+        let sql = QueryBuilder::new()
+            .dialect(Postgres)
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .insert("status", "delayed")
+            .on_conflict("flight_number")
+            .do_update("status")
+            .do_update_where(|query| {
+                query.where_closure(|query| {
+                    query
+                        .where_not("flights.status", "cancelled")
+                        .or_where("flights.status", "scheduled")
+                })
+            })
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            r#"INSERT INTO "flights" ("flight_number", "status") VALUES ('KL123', 'delayed') ON CONFLICT ("flight_number") DO UPDATE SET "status" = EXCLUDED."status" WHERE ("flights"."status" != 'cancelled' OR "flights"."status" = 'scheduled')"#
+        );
+    }
+
+    #[test]
+    fn test_postgres_upsert_without_conflict_target() {
+        let result = QueryBuilder::new()
+            .dialect(Postgres)
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .insert("status", "delayed")
+            .do_update("status")
+            .sql();
+
+        assert_eq!(result, Err(EloquentError::MissingConflictTarget));
+    }
+}
@@ -0,0 +1,276 @@
+use super::{DatePart, Dialect, DropIndexSyntax, Feature, LateralSyntax};
+use crate::ColumnType;
+
+/// Microsoft SQL Server dialect.
+#[derive(Debug, Clone, Copy)]
+pub struct SqlServer;
+
+impl Dialect for SqlServer {
+    fn name(&self) -> &'static str {
+        "SQL Server"
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("@p{}", index)
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        format!("[{}]", identifier)
+    }
+
+    fn boolean_literal(&self, value: bool) -> String {
+        if value {
+            "1".to_string()
+        } else {
+            "0".to_string()
+        }
+    }
+
+    fn with_recursive_keyword(&self) -> &'static str {
+        "WITH"
+    }
+
+    fn not_equal_operator(&self) -> &'static str {
+        "<>"
+    }
+
+    fn date_function(&self, part: DatePart, column: &str) -> String {
+        match part {
+            DatePart::Date => format!("CAST({} AS DATE)", column),
+            _ => format!("{}({})", part, column),
+        }
+    }
+
+    fn exists_expression(&self, subquery: &str) -> String {
+        format!("CASE WHEN EXISTS({}) THEN 1 ELSE 0 END", subquery)
+    }
+
+    fn select_prefix(&self, limit: Option<u64>, offset: Option<u64>) -> Option<String> {
+        match (limit, offset) {
+            (Some(limit), None) => Some(format!("TOP {}", limit)),
+            _ => None,
+        }
+    }
+
+    fn limit_offset(&self, limit: Option<u64>, offset: Option<u64>, ordered: bool) -> String {
+        let Some(offset) = offset else {
+            return String::new();
+        };
+
+        let mut sql = String::new();
+
+        // OFFSET ... FETCH is only allowed after an ORDER BY clause
+        if !ordered {
+            sql.push_str(" ORDER BY (SELECT NULL)");
+        }
+
+        sql.push_str(&format!(" OFFSET {} ROWS", offset));
+
+        if let Some(limit) = limit {
+            sql.push_str(&format!(" FETCH NEXT {} ROWS ONLY", limit));
+        }
+
+        sql
+    }
+
+    fn lateral_syntax(&self) -> LateralSyntax {
+        LateralSyntax::Apply
+    }
+
+    fn column_type(&self, column_type: ColumnType) -> String {
+        match column_type {
+            ColumnType::Integer => "INT".to_string(),
+            ColumnType::Text => "NVARCHAR(MAX)".to_string(),
+            ColumnType::Varchar(length) => format!("NVARCHAR({})", length),
+            ColumnType::Boolean => "BIT".to_string(),
+            ColumnType::Timestamp => "DATETIME2".to_string(),
+            _ => column_type.to_string(),
+        }
+    }
+
+    fn add_column_keyword(&self) -> &'static str {
+        "ADD"
+    }
+
+    fn drop_index_syntax(&self) -> DropIndexSyntax {
+        DropIndexSyntax::OnTable
+    }
+
+    fn begin_transaction(&self) -> &'static str {
+        "BEGIN TRANSACTION"
+    }
+
+    fn commit_transaction(&self) -> &'static str {
+        "COMMIT TRANSACTION"
+    }
+
+    fn rollback_transaction(&self) -> &'static str {
+        "ROLLBACK TRANSACTION"
+    }
+
+    fn table_missing_guard(&self, table: &str) -> Option<String> {
+        Some(format!(
+            "IF OBJECT_ID(N'{}', N'U') IS NULL",
+            table.replace('\'', "''")
+        ))
+    }
+
+    fn supports(&self, feature: Feature) -> bool {
+        !matches!(
+            feature,
+            Feature::Upsert
+                | Feature::UpsertWhere
+                | Feature::Returning
+                | Feature::NaturalJoin
+                | Feature::IfNotExists
+                | Feature::RenameColumn
+                | Feature::ConcurrentIndex
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        error::EloquentError, ColumnDefinition, ColumnType, QueryBuilder, SchemaBuilder, SqlServer,
+        SubqueryBuilder,
+    };
+
+    #[test]
+    fn test_sql_server_top() {
+        let sql = QueryBuilder::new()
+            .dialect(SqlServer)
+            .table("flights")
+            .select("origin")
+            .where_not("is_cancelled", true)
+            .limit(10)
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT TOP 10 [origin] FROM [flights] WHERE [is_cancelled] <> 1"
+        );
+    }
+
+    #[test]
+    fn test_sql_server_offset_fetch() {
+        let (sql, _) = QueryBuilder::new()
+            .dialect(SqlServer)
+            .table("flights")
+            .where_date("departure_date", "2024-10-01")
+            .limit(10)
+            .offset(20)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM [flights] WHERE CAST([departure_date] AS DATE) = @p1 ORDER BY (SELECT NULL) OFFSET 20 ROWS FETCH NEXT 10 ROWS ONLY"
+        );
+    }
+
+    #[test]
+    fn test_sql_server_upsert_is_unsupported() {
+        let result = QueryBuilder::new()
+            .dialect(SqlServer)
+            .table("flights")
+            .insert("flight_number", "KL123")
+            .on_conflict("flight_number")
+            .do_nothing()
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, dialect)) => {
+                assert_eq!(feature, "ON CONFLICT");
+                assert_eq!(dialect, "SQL Server");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_sql_server_natural_join_is_unsupported() {
+        let result = QueryBuilder::new()
+            .dialect(SqlServer)
+            .table("flights")
+            .natural_join("flight_details")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, _)) => {
+                assert_eq!(feature, "NATURAL JOIN")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_sql_server_outer_apply() {
+        let subquery = SubqueryBuilder::new()
+            .table("bookings")
+            .select("booked_at")
+            .where_column("bookings.flight_id", "flights.id");
+
+        let sql = QueryBuilder::new()
+            .dialect(SqlServer)
+            .table("flights")
+            .left_join_lateral(subquery, "latest")
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT * FROM [flights] OUTER APPLY (SELECT [booked_at] FROM [bookings] WHERE [bookings].[flight_id] = [flights].[id]) AS [latest]"
+        );
+    }
+
+    #[test]
+    fn test_sql_server_create_table() {
+        let sql = SchemaBuilder::new()
+            .dialect(SqlServer)
+            .create_table("flights", |table| {
+                table
+                    .column(ColumnDefinition::new("id", ColumnType::Integer).primary_key())
+                    .column(ColumnDefinition::new(
+                        "flight_number",
+                        ColumnType::Varchar(10),
+                    ))
+                    .column(
+                        ColumnDefinition::new("is_cancelled", ColumnType::Boolean).default(false),
+                    )
+            })
+            .alter_table("flights", |table| {
+                table.add_column(ColumnDefinition::new("remarks", ColumnType::Text).nullable())
+            })
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "CREATE TABLE [flights] ([id] INT NOT NULL PRIMARY KEY, [flight_number] NVARCHAR(10) NOT NULL, [is_cancelled] BIT NOT NULL DEFAULT 0); ALTER TABLE [flights] ADD [remarks] NVARCHAR(MAX)"
+        );
+    }
+
+    #[test]
+    fn test_sql_server_create_table_if_not_exists_is_unsupported() {
+        let result = SchemaBuilder::new()
+            .dialect(SqlServer)
+            .create_table("flights", |table| {
+                table
+                    .if_not_exists()
+                    .column(ColumnDefinition::new("id", ColumnType::Integer))
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, _)) => {
+                assert_eq!(feature, "IF NOT EXISTS")
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use super::{DatePart, Dialect, Feature};
+use crate::ColumnType;
+
+/// SQLite dialect.
+#[derive(Debug, Clone, Copy)]
+pub struct Sqlite;
+
+impl Dialect for Sqlite {
+    fn name(&self) -> &'static str {
+        "SQLite"
+    }
+
+    fn placeholder(&self, index: usize) -> String {
+        format!("?{}", index)
+    }
+
+    fn quote_identifier(&self, identifier: &str) -> String {
+        format!("\"{}\"", identifier)
+    }
+
+    fn date_function(&self, part: DatePart, column: &str) -> String {
+        match part {
+            DatePart::Date => format!("DATE({})", column),
+            DatePart::Year => format!("CAST(strftime('%Y', {}) AS INTEGER)", column),
+            DatePart::Month => format!("CAST(strftime('%m', {}) AS INTEGER)", column),
+            DatePart::Day => format!("CAST(strftime('%d', {}) AS INTEGER)", column),
+        }
+    }
+
+    fn limit_offset(&self, limit: Option<u64>, offset: Option<u64>, _ordered: bool) -> String {
+        match (limit, offset) {
+            // SQLite has no OFFSET without LIMIT, a negative limit means no limit
+            (None, Some(offset)) => format!(" LIMIT -1 OFFSET {}", offset),
+            (Some(limit), Some(offset)) => format!(" LIMIT {} OFFSET {}", limit, offset),
+            (Some(limit), None) => format!(" LIMIT {}", limit),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn column_type(&self, column_type: ColumnType) -> String {
+        match column_type {
+            ColumnType::Float => "REAL".to_string(),
+            _ => column_type.to_string(),
+        }
+    }
+
+    fn supports(&self, feature: Feature) -> bool {
+        !matches!(
+            feature,
+            Feature::Lateral | Feature::ConcurrentIndex | Feature::AlterConstraint
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, QueryBuilder, SchemaBuilder, Sqlite, SubqueryBuilder};
+
+    #[test]
+    fn test_sqlite_select() {
+        let (sql, _) = QueryBuilder::new()
+            .dialect(Sqlite)
+            .table("flights")
+            .where_month("departure_date", 10)
+            .where_date("arrival_date", "2024-10-01")
+            .offset(5)
+            .to_sql_with_params()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            r#"SELECT * FROM "flights" WHERE CAST(strftime('%m', "departure_date") AS INTEGER) = ?1 AND DATE("arrival_date") = ?2 LIMIT -1 OFFSET 5"#
+        );
+    }
+
+    #[test]
+    fn test_sqlite_lateral_join_is_unsupported() {
+        let result = QueryBuilder::new()
+            .dialect(Sqlite)
+            .table("flights")
+            .join_lateral(SubqueryBuilder::new().table("bookings"), "bookings")
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, dialect)) => {
+                assert_eq!(feature, "LATERAL");
+                assert_eq!(dialect, "SQLite");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_sqlite_add_constraint_is_unsupported() {
+        let result = SchemaBuilder::new()
+            .dialect(Sqlite)
+            .alter_table("flights", |table| {
+                table.add_unique("flights_number_unique", "flight_number")
+            })
+            .sql();
+
+        match result {
+            Err(EloquentError::UnsupportedByDialect(feature, dialect)) => {
+                assert_eq!(feature, "ALTER TABLE ... CONSTRAINT");
+                assert_eq!(dialect, "SQLite");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
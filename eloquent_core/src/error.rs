@@ -13,6 +13,42 @@ pub enum EloquentError {
     CannotApplyClauseOnDelete(String),
     CannotUseOffsetLimitWithPagination(String),
     InconsistentInsertColumns,
+    UnsupportedByDialect(String, String),
+    DuplicatedCteNames(String),
+    UndefinedCte(String),
+    InconsistentSetOperationColumns(String),
+    UpsertColumnNotInserted(String),
+    CannotApplyClauseOnSelect(String),
+    UnsupportedReturning(String),
+    InconsistentInsertUsingColumns,
+    UnsupportedOperator(String),
+    UnknownTable(String),
+    UnknownColumn(String),
+    NullComparisonOnNonNullableColumn(String),
+    IncompatibleColumnType(String, String),
+    InvalidSchema(String),
+    MissingColumnDefinitions(String),
+    UndeclaredColumn(String),
+    MultiplePrimaryKeys(String),
+    ForeignKeyToUndeclaredColumn(String),
+    MissingIndexColumns(String),
+    DuplicatedMigrationNames(String),
+    UnknownMigration(String),
+    ExecutionFailed(String),
+    RowNotFound,
+    InvalidCursor(String),
+    InconsistentCursorValues,
+    CannotCombineNonSelectQuery(String),
+    CannotApplyClauseOnSetOperationBranch(String),
+    MissingConflictTarget,
+    NotAnAggregateFunction(String),
+    MissingCaseBranches,
+    MissingJoinConditions(String),
+    MissingPrimaryKey(String),
+    MissingKeysetColumns,
+    NullCursorValue,
+    PageOutOfRange(u64, u64),
+    BindInParameterizedQuery(u32),
 }
 
 impl std::error::Error for EloquentError {}
@@ -62,6 +98,120 @@ impl std::fmt::Display for EloquentError {
                 f,
                 "INSERT statement has inconsistent column counts across rows"
             ),
+            EloquentError::UnsupportedByDialect(feature, dialect) => {
+                write!(f, "'{}' is not supported by {}", feature, dialect)
+            }
+            EloquentError::DuplicatedCteNames(name) => {
+                write!(f, "Duplicated CTE name '{}'", name)
+            }
+            EloquentError::UndefinedCte(name) => {
+                write!(f, "CTE '{}' is referenced before it is defined", name)
+            }
+            EloquentError::InconsistentSetOperationColumns(operator) => write!(
+                f,
+                "{} branches select a different number of columns",
+                operator
+            ),
+            EloquentError::UpsertColumnNotInserted(column) => {
+                write!(
+                    f,
+                    "ON CONFLICT column '{}' is not an inserted column",
+                    column
+                )
+            }
+            EloquentError::CannotApplyClauseOnSelect(clause) => {
+                write!(f, "Cannot apply clause '{}' on SELECT", clause)
+            }
+            EloquentError::UnsupportedReturning(dialect) => {
+                write!(f, "RETURNING is not supported by {}", dialect)
+            }
+            EloquentError::InconsistentInsertUsingColumns => write!(
+                f,
+                "INSERT columns and subquery select a different number of columns"
+            ),
+            EloquentError::UnsupportedOperator(operator) => {
+                write!(f, "Unsupported operator '{}'", operator)
+            }
+            EloquentError::UnknownTable(table) => write!(f, "Unknown table '{}'", table),
+            EloquentError::UnknownColumn(column) => write!(f, "Unknown column '{}'", column),
+            EloquentError::NullComparisonOnNonNullableColumn(column) => {
+                write!(f, "Column '{}' is not nullable", column)
+            }
+            EloquentError::IncompatibleColumnType(column, column_type) => {
+                write!(
+                    f,
+                    "Value is not compatible with column '{}' of type {}",
+                    column, column_type
+                )
+            }
+            EloquentError::InvalidSchema(error) => write!(f, "Invalid schema: {}", error),
+            EloquentError::MissingColumnDefinitions(table) => {
+                write!(f, "Table '{}' has no columns", table)
+            }
+            EloquentError::UndeclaredColumn(column) => {
+                write!(f, "Column '{}' is not declared", column)
+            }
+            EloquentError::MultiplePrimaryKeys(table) => {
+                write!(f, "Table '{}' has multiple primary keys", table)
+            }
+            EloquentError::ForeignKeyToUndeclaredColumn(column) => {
+                write!(f, "Foreign key references undeclared column '{}'", column)
+            }
+            EloquentError::MissingIndexColumns(index) => {
+                write!(f, "Index '{}' has no columns", index)
+            }
+            EloquentError::DuplicatedMigrationNames(migration) => {
+                write!(f, "Duplicated migration name '{}'", migration)
+            }
+            EloquentError::UnknownMigration(migration) => {
+                write!(f, "Applied migration '{}' is not defined", migration)
+            }
+            EloquentError::ExecutionFailed(error) => write!(f, "Execution failed: {}", error),
+            EloquentError::RowNotFound => write!(f, "Query returned no rows"),
+            EloquentError::InvalidCursor(cursor) => write!(f, "Invalid cursor '{}'", cursor),
+            EloquentError::InconsistentCursorValues => {
+                write!(f, "Cursor values do not match the keyset columns")
+            }
+            EloquentError::CannotCombineNonSelectQuery(operator) => {
+                write!(f, "{} branches must be SELECT queries", operator)
+            }
+            EloquentError::CannotApplyClauseOnSetOperationBranch(clause) => {
+                write!(
+                    f,
+                    "Cannot apply clause '{}' on a set operation branch",
+                    clause
+                )
+            }
+            EloquentError::MissingConflictTarget => {
+                write!(f, "ON CONFLICT DO UPDATE requires conflict target columns")
+            }
+            EloquentError::NotAnAggregateFunction(function) => {
+                write!(f, "'{}' is not an aggregate function", function)
+            }
+            EloquentError::MissingCaseBranches => write!(f, "CASE expression has no WHEN branches"),
+            EloquentError::MissingJoinConditions(table) => {
+                write!(f, "JOIN on '{}' has no ON conditions", table)
+            }
+            EloquentError::MissingPrimaryKey(table) => {
+                write!(f, "Table '{}' has no primary key to update a row by", table)
+            }
+            EloquentError::MissingKeysetColumns => write!(f, "Keyset has no columns to order by"),
+            EloquentError::NullCursorValue => {
+                write!(
+                    f,
+                    "Cursor values cannot be NULL, order the keyset by non-nullable columns"
+                )
+            }
+            EloquentError::PageOutOfRange(page, per_page) => {
+                write!(f, "Page {} of {} rows is out of range", page, per_page)
+            }
+            EloquentError::BindInParameterizedQuery(index) => {
+                write!(
+                    f,
+                    "bind({}) cannot be used with generated parameters, bind the value itself",
+                    index
+                )
+            }
         }
     }
 }
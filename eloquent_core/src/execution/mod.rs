@@ -0,0 +1,19 @@
+//! Execute a compiled query with its bound values, behind the `rusqlite`, `sqlx` and
+//! `tokio-postgres` features.
+//!
+//! Every driver offers `fetch_all`, `fetch_one`, `fetch_optional` and `execute`. The rows are
+//! mapped to any type implementing a `FromRow` trait, which is implemented for tuples of columns.
+//! The `rusqlite` and `tokio-postgres` drivers also map a row to a `Vec` of [`Value`](crate::Value)s.
+
+use crate::error::EloquentError;
+
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+#[cfg(feature = "tokio-postgres")]
+pub mod tokio_postgres;
+
+fn execution_failed(error: impl std::fmt::Display) -> EloquentError {
+    EloquentError::ExecutionFailed(error.to_string())
+}
@@ -0,0 +1,313 @@
+//! Execute queries on a SQLite [`Connection`] of `rusqlite`.
+//!
+//! ```
+//! use eloquent_core::{execution::rusqlite::fetch_all, QueryBuilder, Sqlite};
+//! use rusqlite::Connection;
+//!
+//! let connection = Connection::open_in_memory().unwrap();
+//!
+//! connection
+//!     .execute_batch(
+//!         "CREATE TABLE flights (flight_number TEXT, duration_in_min INTEGER);
+//!          INSERT INTO flights VALUES ('KL123', 120), ('KL456', 90);",
+//!     )
+//!     .unwrap();
+//!
+//! let query = QueryBuilder::new()
+//!     .dialect(Sqlite)
+//!     .table("flights")
+//!     .select(vec!["flight_number", "duration_in_min"])
+//!     .where_gt("duration_in_min", 100);
+//!
+//! let flights: Vec<(String, i64)> = fetch_all(&query, &connection).unwrap();
+//!
+//! assert_eq!(flights, vec![("KL123".to_string(), 120)]);
+//! ```
+
+use super::execution_failed;
+use crate::{error::EloquentError, Executor, QueryBuilder, Value};
+use ::rusqlite::{
+    params_from_iter,
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+    Connection, Row, ToSql,
+};
+
+/// Map a row of a result set to a type.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row<'_>) -> ::rusqlite::Result<Self>;
+}
+
+/// Execute the query and map every row.
+pub fn fetch_all<T>(
+    builder: &QueryBuilder,
+    connection: &Connection,
+) -> Result<Vec<T>, EloquentError>
+where
+    T: FromRow,
+{
+    let (sql, params) = builder.to_sql_with_params()?;
+
+    let mut statement = connection.prepare(&sql).map_err(execution_failed)?;
+
+    statement
+        .query_map(params_from_iter(params.iter()), T::from_row)
+        .and_then(|rows| rows.collect())
+        .map_err(execution_failed)
+}
+
+/// Execute the query and map the first row, fails when the query returns no rows.
+pub fn fetch_one<T>(builder: &QueryBuilder, connection: &Connection) -> Result<T, EloquentError>
+where
+    T: FromRow,
+{
+    fetch_optional(builder, connection)?.ok_or(EloquentError::RowNotFound)
+}
+
+/// Execute the query and map the first row, if any.
+pub fn fetch_optional<T>(
+    builder: &QueryBuilder,
+    connection: &Connection,
+) -> Result<Option<T>, EloquentError>
+where
+    T: FromRow,
+{
+    let (sql, params) = builder.to_sql_with_params()?;
+
+    let mut statement = connection.prepare(&sql).map_err(execution_failed)?;
+    let mut rows = statement
+        .query(params_from_iter(params.iter()))
+        .map_err(execution_failed)?;
+
+    match rows.next().map_err(execution_failed)? {
+        Some(row) => T::from_row(row).map(Some).map_err(execution_failed),
+        None => Ok(None),
+    }
+}
+
+/// Execute the statement and return the number of affected rows.
+pub fn execute(builder: &QueryBuilder, connection: &Connection) -> Result<u64, EloquentError> {
+    let (sql, params) = builder.to_sql_with_params()?;
+
+    connection
+        .execute(&sql, params_from_iter(params.iter()))
+        .map(|affected| affected as u64)
+        .map_err(execution_failed)
+}
+
+impl Executor for Connection {
+    fn execute(&mut self, sql: &str, params: &[Value]) -> Result<u64, EloquentError> {
+        Connection::execute(self, sql, params_from_iter(params.iter()))
+            .map(|affected| affected as u64)
+            .map_err(execution_failed)
+    }
+
+    fn query(&mut self, sql: &str, params: &[Value]) -> Result<Vec<Vec<Value>>, EloquentError> {
+        let mut statement = self.prepare(sql).map_err(execution_failed)?;
+
+        statement
+            .query_map(params_from_iter(params.iter()), Vec::<Value>::from_row)
+            .and_then(|rows| rows.collect())
+            .map_err(execution_failed)
+    }
+}
+
+impl ToSql for Value {
+    fn to_sql(&self) -> ::rusqlite::Result<ToSqlOutput<'_>> {
+        match self {
+            Value::Null => Ok(ToSqlOutput::from(::rusqlite::types::Null)),
+            Value::Bool(value) => value.to_sql(),
+            Value::Int(value) => value.to_sql(),
+            Value::UInt(value) => i64::try_from(*value)
+                .map(ToSqlOutput::from)
+                .map_err(|error| ::rusqlite::Error::ToSqlConversionFailure(Box::new(error))),
+            Value::Float(value) => value.to_sql(),
+            Value::Text(value) => value.to_sql(),
+        }
+    }
+}
+
+impl FromSql for Value {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Null => Ok(Value::Null),
+            ValueRef::Integer(value) => Ok(Value::Int(value)),
+            ValueRef::Real(value) => Ok(Value::Float(value)),
+            ValueRef::Text(value) => String::from_utf8(value.to_vec())
+                .map(Value::Text)
+                .map_err(|error| FromSqlError::Other(Box::new(error))),
+            ValueRef::Blob(_) => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+impl FromRow for Vec<Value> {
+    fn from_row(row: &Row<'_>) -> ::rusqlite::Result<Self> {
+        (0..row.as_ref().column_count())
+            .map(|index| row.get(index))
+            .collect()
+    }
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($($index:tt => $column:ident),+) => {
+        impl<$($column: FromSql),+> FromRow for ($($column,)+) {
+            fn from_row(row: &Row<'_>) -> ::rusqlite::Result<Self> {
+                Ok(($(row.get($index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(0 => A);
+impl_from_row_for_tuple!(0 => A, 1 => B);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H);
+
+#[cfg(test)]
+mod tests {
+    use super::{execute, fetch_all, fetch_one, fetch_optional, FromRow};
+    use crate::{error::EloquentError, QueryBuilder, Sqlite, Value};
+    use rusqlite::{Connection, Row};
+
+    #[derive(Debug, PartialEq)]
+    struct Flight {
+        flight_number: String,
+        duration_in_min: i64,
+        gate: Option<String>,
+    }
+
+    impl FromRow for Flight {
+        fn from_row(row: &Row<'_>) -> rusqlite::Result<Self> {
+            Ok(Flight {
+                flight_number: row.get("flight_number")?,
+                duration_in_min: row.get("duration_in_min")?,
+                gate: row.get("gate")?,
+            })
+        }
+    }
+
+    fn connection() -> Connection {
+        let connection = Connection::open_in_memory().unwrap();
+
+        connection
+            .execute_batch(
+                "CREATE TABLE flights (flight_number TEXT, duration_in_min INTEGER, gate TEXT);
+                 INSERT INTO flights VALUES ('KL123', 120, 'D7'), ('KL456', 90, NULL);",
+            )
+            .unwrap();
+
+        connection
+    }
+
+    #[test]
+    fn test_fetch_typed_rows() {
+        let connection = connection();
+
+        let flight: Flight = fetch_one(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .r#where("flight_number", "KL456"),
+            &connection,
+        )
+        .unwrap();
+
+        assert_eq!(
+            flight,
+            Flight {
+                flight_number: "KL456".to_string(),
+                duration_in_min: 90,
+                gate: None,
+            }
+        );
+
+        let rows: Vec<Vec<Value>> = fetch_all(
+            &QueryBuilder::new()
+                .table("flights")
+                .select(vec!["flight_number", "gate"])
+                .order_by_asc("flight_number"),
+            &connection,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rows,
+            vec![
+                vec![
+                    Value::Text("KL123".to_string()),
+                    Value::Text("D7".to_string())
+                ],
+                vec![Value::Text("KL456".to_string()), Value::Null],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fetch_optional_and_one_without_rows() {
+        let connection = connection();
+
+        let query = || {
+            QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .select("flight_number")
+                .where_gt("duration_in_min", 500)
+        };
+
+        let flight: Option<(String,)> = fetch_optional(&query(), &connection).unwrap();
+
+        assert_eq!(flight, None);
+        assert_eq!(
+            fetch_one::<(String,)>(&query(), &connection),
+            Err(EloquentError::RowNotFound)
+        );
+    }
+
+    #[test]
+    fn test_execute_with_bound_values() {
+        let connection = connection();
+
+        let affected = execute(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .update("gate", "E1")
+                .where_null("gate"),
+            &connection,
+        )
+        .unwrap();
+
+        assert_eq!(affected, 1);
+
+        let (gate,): (String,) = fetch_one(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .select("gate")
+                .r#where("flight_number", "KL456"),
+            &connection,
+        )
+        .unwrap();
+
+        assert_eq!(gate, "E1");
+    }
+
+    #[test]
+    fn test_connection_is_an_executor() {
+        let mut connection = connection();
+
+        let rows = QueryBuilder::new()
+            .dialect(Sqlite)
+            .table("flights")
+            .select("duration_in_min")
+            .r#where("gate", "D7")
+            .fetch(&mut connection)
+            .unwrap();
+
+        assert_eq!(rows, vec![vec![Value::Int(120)]]);
+    }
+}
@@ -0,0 +1,345 @@
+//! Execute queries on any `sqlx` executor, e.g. a pool, connection or transaction.
+//!
+//! The rows are mapped with the [`FromRow`] trait of `sqlx`, which can be derived or is already
+//! implemented for tuples of columns.
+//!
+//! The values are encoded for the database of the executor, enabled with the `sqlx-sqlite`,
+//! `sqlx-mysql` or `sqlx-postgres` feature. NULL is bound without a type, so PostgreSQL infers
+//! the type of its parameter from the query, e.g. an `INTEGER` column.
+//!
+//! The returned futures do not borrow the builder, the query is compiled before the first poll.
+
+use super::execution_failed;
+use crate::{error::EloquentError, QueryBuilder, Value};
+use ::sqlx::{Database, Executor, FromRow, IntoArguments};
+use std::future::Future;
+
+/// A database the bound values of a query can be encoded for.
+pub trait EncodeValues: Database {
+    /// Encode the values in the order of their placeholders.
+    fn encode_values<'q>(values: Vec<Value>) -> Result<Self::Arguments<'q>, EloquentError>;
+}
+
+#[cfg(any(
+    feature = "sqlx-sqlite",
+    feature = "sqlx-mysql",
+    feature = "sqlx-postgres"
+))]
+mod encode {
+    use super::{execution_failed, EncodeValues};
+    use crate::{error::EloquentError, Value};
+    use ::sqlx::{encode::IsNull, error::BoxDynError, Arguments, Database, Encode, Type};
+
+    #[cfg(feature = "sqlx-sqlite")]
+    impl EncodeValues for ::sqlx::Sqlite {
+        fn encode_values<'q>(values: Vec<Value>) -> Result<Self::Arguments<'q>, EloquentError> {
+            encode_values::<Self>(values)
+        }
+    }
+
+    #[cfg(feature = "sqlx-mysql")]
+    impl EncodeValues for ::sqlx::MySql {
+        fn encode_values<'q>(values: Vec<Value>) -> Result<Self::Arguments<'q>, EloquentError> {
+            encode_values::<Self>(values)
+        }
+    }
+
+    #[cfg(feature = "sqlx-postgres")]
+    impl EncodeValues for ::sqlx::Postgres {
+        fn encode_values<'q>(values: Vec<Value>) -> Result<Self::Arguments<'q>, EloquentError> {
+            encode_values::<Self>(values)
+        }
+    }
+
+    fn encode_values<'q, DB>(values: Vec<Value>) -> Result<DB::Arguments<'q>, EloquentError>
+    where
+        DB: Database,
+        bool: Encode<'q, DB> + Type<DB>,
+        i64: Encode<'q, DB> + Type<DB>,
+        f64: Encode<'q, DB> + Type<DB>,
+        String: Encode<'q, DB> + Type<DB>,
+        Null: Encode<'q, DB> + Type<DB>,
+    {
+        let mut arguments = DB::Arguments::default();
+
+        for value in values {
+            match value {
+                Value::Null => arguments.add(Null),
+                Value::Bool(value) => arguments.add(value),
+                Value::Int(value) => arguments.add(value),
+                Value::UInt(value) => {
+                    arguments.add(i64::try_from(value).map_err(execution_failed)?)
+                }
+                Value::Float(value) => arguments.add(value),
+                Value::Text(value) => arguments.add(value),
+            }
+            .map_err(execution_failed)?;
+        }
+
+        Ok(arguments)
+    }
+
+    /// A NULL parameter without a type of its own.
+    struct Null;
+
+    impl<'q, DB: Database> Encode<'q, DB> for Null {
+        fn encode_by_ref(
+            &self,
+            _buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            Ok(IsNull::Yes)
+        }
+    }
+
+    // SQLite and MySQL accept a NULL of any type for every column
+    #[cfg(feature = "sqlx-sqlite")]
+    impl Type<::sqlx::Sqlite> for Null {
+        fn type_info() -> ::sqlx::sqlite::SqliteTypeInfo {
+            <String as Type<::sqlx::Sqlite>>::type_info()
+        }
+    }
+
+    #[cfg(feature = "sqlx-mysql")]
+    impl Type<::sqlx::MySql> for Null {
+        fn type_info() -> ::sqlx::mysql::MySqlTypeInfo {
+            <String as Type<::sqlx::MySql>>::type_info()
+        }
+    }
+
+    // OID 0 leaves the type of the parameter to PostgreSQL, like an untyped literal `NULL`
+    #[cfg(feature = "sqlx-postgres")]
+    impl Type<::sqlx::Postgres> for Null {
+        fn type_info() -> ::sqlx::postgres::PgTypeInfo {
+            ::sqlx::postgres::PgTypeInfo::with_oid(::sqlx::postgres::types::Oid(0))
+        }
+    }
+
+    #[cfg(all(test, feature = "sqlx-postgres"))]
+    mod tests {
+        use super::Null;
+        use crate::{execution::sqlx::EncodeValues, Value};
+        use sqlx::{
+            encode::IsNull,
+            postgres::{types::Oid, PgArgumentBuffer},
+            Arguments, Encode, Postgres, Type,
+        };
+
+        #[test]
+        fn test_null_is_bound_without_a_type() {
+            assert_eq!(<Null as Type<Postgres>>::type_info().oid(), Some(Oid(0)));
+            assert!(matches!(
+                Encode::<Postgres>::encode_by_ref(&Null, &mut PgArgumentBuffer::default()),
+                Ok(IsNull::Yes)
+            ));
+
+            let arguments =
+                Postgres::encode_values(vec![Value::Null, Value::Int(120), Value::Null]).unwrap();
+
+            assert_eq!(arguments.len(), 3);
+        }
+    }
+}
+
+/// Execute the query and map every row.
+pub fn fetch_all<'c, DB, T, E>(
+    builder: &QueryBuilder,
+    executor: E,
+) -> impl Future<Output = Result<Vec<T>, EloquentError>> + 'c
+where
+    DB: EncodeValues,
+    for<'q> DB::Arguments<'q>: IntoArguments<'q, DB>,
+    T: Send + Unpin + for<'r> FromRow<'r, DB::Row> + 'c,
+    E: Executor<'c, Database = DB> + 'c,
+{
+    let compiled = builder.to_sql_with_params();
+
+    async move {
+        let (sql, values) = compiled?;
+
+        let rows = ::sqlx::query_as_with::<DB, T, _>(&sql, DB::encode_values(values)?)
+            .fetch_all(executor)
+            .await;
+
+        rows.map_err(execution_failed)
+    }
+}
+
+/// Execute the query and map the first row, fails when the query returns no rows.
+pub fn fetch_one<'c, DB, T, E>(
+    builder: &QueryBuilder,
+    executor: E,
+) -> impl Future<Output = Result<T, EloquentError>> + 'c
+where
+    DB: EncodeValues,
+    for<'q> DB::Arguments<'q>: IntoArguments<'q, DB>,
+    T: Send + Unpin + for<'r> FromRow<'r, DB::Row> + 'c,
+    E: Executor<'c, Database = DB> + 'c,
+{
+    let row = fetch_optional(builder, executor);
+
+    async move { row.await?.ok_or(EloquentError::RowNotFound) }
+}
+
+/// Execute the query and map the first row, if any.
+pub fn fetch_optional<'c, DB, T, E>(
+    builder: &QueryBuilder,
+    executor: E,
+) -> impl Future<Output = Result<Option<T>, EloquentError>> + 'c
+where
+    DB: EncodeValues,
+    for<'q> DB::Arguments<'q>: IntoArguments<'q, DB>,
+    T: Send + Unpin + for<'r> FromRow<'r, DB::Row> + 'c,
+    E: Executor<'c, Database = DB> + 'c,
+{
+    let compiled = builder.to_sql_with_params();
+
+    async move {
+        let (sql, values) = compiled?;
+
+        let row = ::sqlx::query_as_with::<DB, T, _>(&sql, DB::encode_values(values)?)
+            .fetch_optional(executor)
+            .await;
+
+        row.map_err(execution_failed)
+    }
+}
+
+/// Execute the statement and return the result of the database, e.g. the number of affected rows.
+pub fn execute<'c, DB, E>(
+    builder: &QueryBuilder,
+    executor: E,
+) -> impl Future<Output = Result<DB::QueryResult, EloquentError>> + 'c
+where
+    DB: EncodeValues,
+    for<'q> DB::Arguments<'q>: IntoArguments<'q, DB>,
+    E: Executor<'c, Database = DB> + 'c,
+{
+    let compiled = builder.to_sql_with_params();
+
+    async move {
+        let (sql, values) = compiled?;
+
+        let result = ::sqlx::query_with::<DB, _>(&sql, DB::encode_values(values)?)
+            .execute(executor)
+            .await;
+
+        result.map_err(execution_failed)
+    }
+}
+
+#[cfg(all(test, feature = "sqlx-sqlite"))]
+mod tests {
+    use super::{execute, fetch_all, fetch_one, fetch_optional};
+    use crate::{error::EloquentError, QueryBuilder, Sqlite, Value};
+    use sqlx::{Connection, SqliteConnection};
+
+    async fn connection() -> SqliteConnection {
+        let mut connection = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+
+        sqlx::raw_sql(
+            "CREATE TABLE flights (flight_number TEXT, duration_in_min INTEGER, gate TEXT);
+             INSERT INTO flights VALUES ('KL123', 120, 'D7'), ('KL456', 90, NULL);",
+        )
+        .execute(&mut connection)
+        .await
+        .unwrap();
+
+        connection
+    }
+
+    #[tokio::test]
+    async fn test_fetch_typed_rows() {
+        let mut connection = connection().await;
+
+        let flights: Vec<(String, i64, Option<String>)> = fetch_all(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .where_lt("duration_in_min", 150)
+                .order_by_asc("flight_number"),
+            &mut connection,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            flights,
+            vec![
+                ("KL123".to_string(), 120, Some("D7".to_string())),
+                ("KL456".to_string(), 90, None),
+            ]
+        );
+
+        let flight: Option<(String,)> = fetch_optional(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .select("flight_number")
+                .r#where("gate", "D7"),
+            &mut connection,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(flight, Some(("KL123".to_string(),)));
+    }
+
+    #[tokio::test]
+    async fn test_execute_and_fetch_one() {
+        let mut connection = connection().await;
+
+        let result = execute(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .delete()
+                .where_gt("duration_in_min", 100),
+            &mut connection,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result.rows_affected(), 1);
+
+        let result = fetch_one::<_, (String,), _>(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .select("flight_number")
+                .r#where("flight_number", "KL123"),
+            &mut connection,
+        )
+        .await;
+
+        assert_eq!(result, Err(EloquentError::RowNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_null_is_bound_to_integer_columns() {
+        let mut connection = connection().await;
+
+        execute(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .update("duration_in_min", Value::Null)
+                .r#where("flight_number", "KL123"),
+            &mut connection,
+        )
+        .await
+        .unwrap();
+
+        let flight: (String, Option<i64>) = fetch_one(
+            &QueryBuilder::new()
+                .dialect(Sqlite)
+                .table("flights")
+                .select(vec!["flight_number", "duration_in_min"])
+                .r#where("flight_number", "KL123"),
+            &mut connection,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(flight, ("KL123".to_string(), None));
+    }
+}
@@ -0,0 +1,270 @@
+//! Execute queries on a `tokio-postgres` client or transaction.
+//!
+//! The bound values are encoded for the parameter types PostgreSQL infers from the query, so an
+//! integer can be bound to an `INT4` column and a float to a `REAL` column. Text is only bound to
+//! text parameters, a value for e.g. a `TIMESTAMP`, `DATE`, `UUID` or `NUMERIC` parameter fails
+//! with an error instead of being sent as malformed binary data, so such columns are compared
+//! with a cast, e.g. `departure_time::text`.
+//!
+//! The returned futures do not borrow the builder, the query is compiled before the first poll.
+
+use super::execution_failed;
+use crate::{error::EloquentError, QueryBuilder, Value};
+use ::tokio_postgres::{
+    types::{to_sql_checked, FromSql, IsNull, ToSql, Type},
+    GenericClient, Row,
+};
+use bytes::BytesMut;
+use std::{error::Error, future::Future};
+
+/// Map a row of a result set to a type.
+pub trait FromRow: Sized {
+    fn from_row(row: &Row) -> Result<Self, ::tokio_postgres::Error>;
+}
+
+/// Execute the query and map every row.
+pub fn fetch_all<'c, T, C>(
+    builder: &QueryBuilder,
+    client: &'c C,
+) -> impl Future<Output = Result<Vec<T>, EloquentError>> + 'c
+where
+    T: FromRow + 'c,
+    C: GenericClient + Sync,
+{
+    let compiled = builder.to_sql_with_params();
+
+    async move {
+        let (sql, values) = compiled?;
+
+        client
+            .query(&sql, &params(&values))
+            .await
+            .and_then(|rows| rows.iter().map(T::from_row).collect())
+            .map_err(execution_failed)
+    }
+}
+
+/// Execute the query and map the first row, fails when the query returns no rows.
+pub fn fetch_one<'c, T, C>(
+    builder: &QueryBuilder,
+    client: &'c C,
+) -> impl Future<Output = Result<T, EloquentError>> + 'c
+where
+    T: FromRow + 'c,
+    C: GenericClient + Sync,
+{
+    let row = fetch_optional(builder, client);
+
+    async move { row.await?.ok_or(EloquentError::RowNotFound) }
+}
+
+/// Execute the query and map the first row, if any.
+pub fn fetch_optional<'c, T, C>(
+    builder: &QueryBuilder,
+    client: &'c C,
+) -> impl Future<Output = Result<Option<T>, EloquentError>> + 'c
+where
+    T: FromRow + 'c,
+    C: GenericClient + Sync,
+{
+    let rows = fetch_all::<T, C>(builder, client);
+
+    async move { Ok(rows.await?.into_iter().next()) }
+}
+
+/// Execute the statement and return the number of affected rows.
+pub fn execute<'c, C>(
+    builder: &QueryBuilder,
+    client: &'c C,
+) -> impl Future<Output = Result<u64, EloquentError>> + 'c
+where
+    C: GenericClient + Sync,
+{
+    let compiled = builder.to_sql_with_params();
+
+    async move {
+        let (sql, values) = compiled?;
+
+        client
+            .execute(&sql, &params(&values))
+            .await
+            .map_err(execution_failed)
+    }
+}
+
+fn params(values: &[Value]) -> Vec<&(dyn ToSql + Sync)> {
+    values
+        .iter()
+        .map(|value| value as &(dyn ToSql + Sync))
+        .collect()
+}
+
+impl ToSql for Value {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        match self {
+            Value::Null => Ok(IsNull::Yes),
+            Value::Bool(value) if *ty == Type::BOOL => value.to_sql(ty, out),
+            Value::Int(value) => integer_to_sql(*value, ty, out),
+            Value::UInt(value) => integer_to_sql(i64::try_from(*value)?, ty, out),
+            Value::Float(value) if *ty == Type::FLOAT4 => (*value as f32).to_sql(ty, out),
+            Value::Float(value) if *ty == Type::FLOAT8 => value.to_sql(ty, out),
+            Value::Text(value) if <String as ToSql>::accepts(ty) => value.to_sql(ty, out),
+            value => Err(format!("cannot bind {:?} to a parameter of type {}", value, ty).into()),
+        }
+    }
+
+    // NULL can be bound to a parameter of any type, the other values are checked by `to_sql`
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+
+    to_sql_checked!();
+}
+
+fn integer_to_sql(
+    value: i64,
+    ty: &Type,
+    out: &mut BytesMut,
+) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    match *ty {
+        Type::INT2 => i16::try_from(value)?.to_sql(ty, out),
+        Type::INT4 => i32::try_from(value)?.to_sql(ty, out),
+        Type::FLOAT4 => (value as f32).to_sql(ty, out),
+        Type::FLOAT8 => (value as f64).to_sql(ty, out),
+        Type::INT8 => value.to_sql(ty, out),
+        _ => Err(format!("cannot bind an integer to a parameter of type {}", ty).into()),
+    }
+}
+
+impl<'a> FromSql<'a> for Value {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let value = match *ty {
+            Type::BOOL => Value::Bool(bool::from_sql(ty, raw)?),
+            Type::INT2 => Value::Int(i16::from_sql(ty, raw)?.into()),
+            Type::INT4 => Value::Int(i32::from_sql(ty, raw)?.into()),
+            Type::INT8 => Value::Int(i64::from_sql(ty, raw)?),
+            Type::FLOAT4 => Value::Float(f32::from_sql(ty, raw)?.into()),
+            Type::FLOAT8 => Value::Float(f64::from_sql(ty, raw)?),
+            _ => Value::Text(String::from_sql(ty, raw)?),
+        };
+
+        Ok(value)
+    }
+
+    fn from_sql_null(_ty: &Type) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Value::Null)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(
+            *ty,
+            Type::BOOL | Type::INT2 | Type::INT4 | Type::INT8 | Type::FLOAT4 | Type::FLOAT8
+        ) || <String as FromSql>::accepts(ty)
+    }
+}
+
+impl FromRow for Vec<Value> {
+    fn from_row(row: &Row) -> Result<Self, ::tokio_postgres::Error> {
+        (0..row.len()).map(|index| row.try_get(index)).collect()
+    }
+}
+
+macro_rules! impl_from_row_for_tuple {
+    ($($index:tt => $column:ident),+) => {
+        impl<$($column),+> FromRow for ($($column,)+)
+        where
+            $($column: for<'a> FromSql<'a>),+
+        {
+            fn from_row(row: &Row) -> Result<Self, ::tokio_postgres::Error> {
+                Ok(($(row.try_get($index)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_row_for_tuple!(0 => A);
+impl_from_row_for_tuple!(0 => A, 1 => B);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_from_row_for_tuple!(0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H);
+
+#[cfg(test)]
+mod tests {
+    use crate::Value;
+    use bytes::BytesMut;
+    use tokio_postgres::types::{FromSql, IsNull, ToSql, Type};
+
+    fn encode(value: Value, ty: &Type) -> BytesMut {
+        let mut out = BytesMut::new();
+
+        assert!(matches!(value.to_sql(ty, &mut out), Ok(IsNull::No)));
+
+        out
+    }
+
+    #[test]
+    fn test_values_are_encoded_for_the_parameter_type() {
+        assert_eq!(&encode(Value::Int(120), &Type::INT4)[..], &[0, 0, 0, 120]);
+        assert_eq!(&encode(Value::UInt(120), &Type::INT2)[..], &[0, 120]);
+        assert_eq!(encode(Value::Int(120), &Type::INT8).len(), 8);
+        assert_eq!(
+            &encode(Value::Text("KL".to_string()), &Type::TEXT)[..],
+            b"KL"
+        );
+
+        let mut out = BytesMut::new();
+
+        assert!(Value::Int(i64::MAX).to_sql(&Type::INT4, &mut out).is_err());
+        assert!(matches!(
+            Value::Null.to_sql(&Type::INT4, &mut out),
+            Ok(IsNull::Yes)
+        ));
+    }
+
+    #[test]
+    fn test_values_are_not_encoded_for_other_parameter_types() {
+        let mut out = BytesMut::new();
+
+        let error = Value::Text("2024-01-01".to_string())
+            .to_sql_checked(&Type::TIMESTAMP, &mut out)
+            .err()
+            .unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            r#"cannot bind Text("2024-01-01") to a parameter of type timestamp"#
+        );
+        assert!(matches!(
+            Value::Null.to_sql_checked(&Type::TIMESTAMP, &mut out),
+            Ok(IsNull::Yes)
+        ));
+        assert!(Value::Text("KL123".to_string())
+            .to_sql(&Type::UUID, &mut out)
+            .is_err());
+        assert!(Value::Bool(true).to_sql(&Type::INT4, &mut out).is_err());
+        assert!(Value::Float(1.5).to_sql(&Type::NUMERIC, &mut out).is_err());
+        assert!(Value::Int(1).to_sql(&Type::TEXT, &mut out).is_err());
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_values_are_decoded_by_column_type() {
+        assert_eq!(
+            Value::from_sql(&Type::INT4, &[0, 0, 0, 90]).unwrap(),
+            Value::Int(90)
+        );
+        assert_eq!(
+            Value::from_sql(&Type::VARCHAR, b"KL123").unwrap(),
+            Value::Text("KL123".to_string())
+        );
+        assert_eq!(Value::from_sql_null(&Type::TEXT).unwrap(), Value::Null);
+        assert!(!<Value as FromSql>::accepts(&Type::BYTEA));
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{error::EloquentError, Value};
+use std::collections::VecDeque;
+
+/// Runs compiled statements with their bound values against a database.
+///
+/// Queries are executed on an executor with [`QueryBuilder::execute`](crate::QueryBuilder::execute)
+/// and [`QueryBuilder::fetch`](crate::QueryBuilder::fetch), so application code can be written
+/// against this trait and tested with a [`RecordingExecutor`].
+pub trait Executor {
+    /// Execute a statement and return the number of affected rows.
+    fn execute(&mut self, sql: &str, params: &[Value]) -> Result<u64, EloquentError>;
+
+    /// Execute a query and return its rows, with the values in the order of the selected columns.
+    fn query(&mut self, sql: &str, params: &[Value]) -> Result<Vec<Vec<Value>>, EloquentError>;
+}
+
+/// An executor for tests that records every statement and returns canned rows.
+///
+/// Every query returns the next result set added with [`RecordingExecutor::rows`], or no rows
+/// when there are none left. Statements that are executed affect no rows.
+#[cfg_attr(
+    not(feature = "bind-placeholder-questionmark"),
+    doc = r##"```
+use eloquent_core::{QueryBuilder, RecordingExecutor, Value};
+
+let mut executor = RecordingExecutor::new().rows(vec![vec![Value::Text("KL123".to_string())]]);
+
+let rows = QueryBuilder::new()
+    .table("flights")
+    .select("flight_number")
+    .where_gt("duration_in_min", 120)
+    .fetch(&mut executor)
+    .unwrap();
+
+assert_eq!(rows, vec![vec![Value::Text("KL123".to_string())]]);
+assert_eq!(
+    executor.statements(),
+    &[(
+        "SELECT flight_number FROM flights WHERE duration_in_min > $1".to_string(),
+        vec![Value::Int(120)]
+    )]
+);
+```
+"##
+)]
+#[derive(Debug, Default)]
+pub struct RecordingExecutor {
+    statements: Vec<(String, Vec<Value>)>,
+    rows: VecDeque<Vec<Vec<Value>>>,
+}
+
+impl RecordingExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue a result set, the queued result sets are returned by the queries in order.
+    pub fn rows(mut self, rows: Vec<Vec<Value>>) -> Self {
+        self.rows.push_back(rows);
+
+        self
+    }
+
+    /// The recorded statements with their bound values, in the order they were issued.
+    pub fn statements(&self) -> &[(String, Vec<Value>)] {
+        &self.statements
+    }
+
+    /// The SQL of the recorded statements, in the order they were issued.
+    pub fn sql(&self) -> Vec<&str> {
+        self.statements
+            .iter()
+            .map(|(sql, _)| sql.as_str())
+            .collect()
+    }
+
+    /// Forget the recorded statements, the remaining result sets are kept.
+    pub fn clear(&mut self) {
+        self.statements.clear();
+    }
+}
+
+impl Executor for RecordingExecutor {
+    fn execute(&mut self, sql: &str, params: &[Value]) -> Result<u64, EloquentError> {
+        self.statements.push((sql.to_string(), params.to_vec()));
+
+        Ok(0)
+    }
+
+    fn query(&mut self, sql: &str, params: &[Value]) -> Result<Vec<Vec<Value>>, EloquentError> {
+        self.statements.push((sql.to_string(), params.to_vec()));
+
+        Ok(self.rows.pop_front().unwrap_or_default())
+    }
+}
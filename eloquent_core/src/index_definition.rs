@@ -0,0 +1,64 @@
+use crate::{Columnable, Condition, Logic, QueryBuilder};
+
+/// The columns and options of a `CREATE INDEX` statement, built inside the closure of
+/// [`SchemaBuilder::create_index`](crate::SchemaBuilder::create_index).
+pub struct IndexDefinition {
+    pub(crate) name: String,
+    pub(crate) table: String,
+    pub(crate) columns: Vec<String>,
+    pub(crate) unique: bool,
+    pub(crate) concurrently: bool,
+    pub(crate) conditions: Vec<Condition>,
+    pub(crate) closures: Vec<(Logic, Vec<Condition>)>,
+}
+
+impl IndexDefinition {
+    pub(crate) fn new(name: &str, table: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            table: table.to_string(),
+            columns: Vec::new(),
+            unique: false,
+            concurrently: false,
+            conditions: Vec::new(),
+            closures: Vec::new(),
+        }
+    }
+
+    /// Add one or more columns to the index.
+    pub fn columns<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.columns.extend(columns.to_columns());
+
+        self
+    }
+
+    /// Only allow unique values in the indexed columns.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+
+        self
+    }
+
+    /// Build the index without locking the table against writes.
+    pub fn concurrently(mut self) -> Self {
+        self.concurrently = true;
+
+        self
+    }
+
+    /// Only index the rows matching the conditions, which are built inside the closure.
+    pub fn partial<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(QueryBuilder) -> QueryBuilder,
+    {
+        let nested_builder = closure(QueryBuilder::new());
+
+        self.conditions = nested_builder.conditions;
+        self.closures = nested_builder.closures;
+
+        self
+    }
+}
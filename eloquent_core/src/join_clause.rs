@@ -0,0 +1,146 @@
+use crate::{error::EloquentError, Bindings, Comparable, Field, Logic, Operator, ToSql};
+
+/// The `ON` conditions of a join, built inside the closure of [`QueryBuilder::join_on`](crate::QueryBuilder::join_on).
+///
+/// ```
+/// use eloquent_core::QueryBuilder;
+///
+/// let result = QueryBuilder::new()
+///     .table("flights")
+///     .join_on("fares", |join| {
+///         join.on("fares.flight_id", "=", "flights.id")
+///             .and_on("fares.valid_from", "<=", "flights.departure_date")
+///             .on_value("fares.is_active", true)
+///     });
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "SELECT * FROM flights JOIN fares ON fares.flight_id = flights.id AND fares.valid_from <= flights.departure_date AND fares.is_active = true"
+/// );
+/// ```
+pub struct JoinClause {
+    conditions: Vec<JoinCondition>,
+}
+
+struct JoinCondition {
+    logic: Logic,
+    left_hand: String,
+    operator: String,
+    right_hand: JoinOperand,
+}
+
+enum JoinOperand {
+    Column(String),
+    Value(Box<dyn ToSql>),
+}
+
+impl JoinClause {
+    pub(crate) fn new() -> Self {
+        Self {
+            conditions: Vec::new(),
+        }
+    }
+
+    pub(crate) fn columns(left_hand: impl Field, right_hand: impl Field) -> Self {
+        Self::new().on(left_hand, "=", right_hand)
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    /// Compare two columns, combined with the previous condition using `AND`.
+    pub fn on(self, left_hand: impl Field, operator: &str, right_hand: impl Field) -> Self {
+        self.add_condition(
+            Logic::And,
+            left_hand,
+            operator,
+            JoinOperand::Column(right_hand.qualified_field_name()),
+        )
+    }
+
+    /// Compare two columns, combined with the previous condition using `AND`.
+    pub fn and_on(self, left_hand: impl Field, operator: &str, right_hand: impl Field) -> Self {
+        self.on(left_hand, operator, right_hand)
+    }
+
+    /// Compare two columns, combined with the previous condition using `OR`.
+    pub fn or_on(self, left_hand: impl Field, operator: &str, right_hand: impl Field) -> Self {
+        self.add_condition(
+            Logic::Or,
+            left_hand,
+            operator,
+            JoinOperand::Column(right_hand.qualified_field_name()),
+        )
+    }
+
+    /// Compare a column with a bound value, combined with the previous condition using `AND`.
+    pub fn on_value<V>(self, column: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_condition(Logic::And, column, "=", JoinOperand::Value(Box::new(value)))
+    }
+
+    /// Compare a column with a bound value, combined with the previous condition using `OR`.
+    pub fn or_on_value<V>(self, column: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_condition(Logic::Or, column, "=", JoinOperand::Value(Box::new(value)))
+    }
+
+    fn add_condition(
+        mut self,
+        logic: Logic,
+        left_hand: impl Field,
+        operator: &str,
+        right_hand: JoinOperand,
+    ) -> Self {
+        self.conditions.push(JoinCondition {
+            logic,
+            left_hand: left_hand.qualified_field_name(),
+            operator: operator.to_string(),
+            right_hand,
+        });
+
+        self
+    }
+
+    pub(crate) fn format(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        let mut sql = String::new();
+
+        for (i, condition) in self.conditions.iter().enumerate() {
+            if i > 0 {
+                sql.push_str(match condition.logic {
+                    Logic::And => " AND ",
+                    Logic::Or => " OR ",
+                });
+            }
+
+            let operator = match Operator::from_comparison(&condition.operator) {
+                Some(Operator::NotEqual) => bindings.dialect().not_equal_operator().to_string(),
+                Some(operator) => operator.to_string(),
+                None => {
+                    return Err(EloquentError::UnsupportedOperator(
+                        condition.operator.clone(),
+                    ))
+                }
+            };
+
+            let right_hand = match &condition.right_hand {
+                JoinOperand::Column(column) => bindings.identifier(column),
+                JoinOperand::Value(value) => value.to_sql_bound(bindings)?,
+            };
+
+            sql.push_str(&format!(
+                "{} {} {}",
+                bindings.identifier(&condition.left_hand),
+                operator,
+                right_hand
+            ));
+        }
+
+        Ok(sql)
+    }
+}
@@ -0,0 +1,388 @@
+use crate::{error::EloquentError, Bindings, Order, ToSql, Value};
+
+/// Keyset pagination over one or more ordered columns, e.g. `(created_at DESC, id DESC)`.
+///
+/// The query is ordered by the keyset columns and only the rows after (or before) the cursor are
+/// selected, so the page does not shift when rows are inserted while paging.
+///
+/// ```
+/// use eloquent_core::{Cursor, Keyset, QueryBuilder, Value};
+///
+/// let cursor = Cursor::next(vec![Value::Text("2024-01-01".to_string()), Value::Int(42)]);
+///
+/// let keyset = Keyset::new(25)
+///     .order_by_desc("created_at")
+///     .order_by_desc("id")
+///     .cursor(cursor);
+///
+/// let result = QueryBuilder::new()
+///     .table("posts")
+///     .r#where("published", true)
+///     .keyset(keyset);
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "SELECT * FROM posts WHERE published = true AND (created_at < '2024-01-01' OR (created_at = '2024-01-01' AND id < 42)) ORDER BY created_at DESC, id DESC LIMIT 25"
+/// );
+/// ```
+pub struct Keyset {
+    columns: Vec<(String, Order)>,
+    cursor: Option<(Direction, Vec<Box<dyn ToSql>>)>,
+    per_page: u64,
+}
+
+/// The position of a page in a keyset, handed to clients as an opaque token.
+///
+/// ```
+/// use eloquent_core::{Cursor, Direction, Value};
+///
+/// let cursor = Cursor::previous(vec![Value::Int(42)]);
+/// let token = cursor.encode();
+///
+/// let decoded = Cursor::decode(&token).unwrap();
+///
+/// assert_eq!(decoded.direction(), Direction::Previous);
+/// assert_eq!(decoded.values(), &[Value::Int(42)]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cursor {
+    direction: Direction,
+    values: Vec<Value>,
+}
+
+/// The direction to page in from a cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The rows after the cursor, in the order of the keyset.
+    Next,
+    /// The rows before the cursor, in the reverse order of the keyset.
+    Previous,
+}
+
+impl Keyset {
+    pub fn new(per_page: u64) -> Self {
+        Self {
+            columns: Vec::new(),
+            cursor: None,
+            per_page,
+        }
+    }
+
+    /// Order the keyset by a column in ascending order.
+    pub fn order_by_asc(mut self, column: &str) -> Self {
+        self.columns.push((column.to_string(), Order::Asc));
+
+        self
+    }
+
+    /// Order the keyset by a column in descending order.
+    pub fn order_by_desc(mut self, column: &str) -> Self {
+        self.columns.push((column.to_string(), Order::Desc));
+
+        self
+    }
+
+    /// Start the page at a cursor, with a non-NULL value for every keyset column.
+    pub fn cursor(self, cursor: Cursor) -> Self {
+        let values = cursor
+            .values
+            .into_iter()
+            .map(|value| Box::new(value) as Box<dyn ToSql>)
+            .collect();
+
+        self.cursor_values(cursor.direction, values)
+    }
+
+    /// Start the page at any values, e.g. a `bind` placeholder that is kept inline.
+    pub(crate) fn cursor_values(
+        mut self,
+        direction: Direction,
+        values: Vec<Box<dyn ToSql>>,
+    ) -> Self {
+        self.cursor = Some((direction, values));
+
+        self
+    }
+
+    pub(crate) fn per_page(&self) -> u64 {
+        self.per_page
+    }
+
+    pub(crate) fn has_cursor(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    pub(crate) fn has_columns(&self) -> bool {
+        !self.columns.is_empty()
+    }
+
+    pub(crate) fn has_consistent_cursor(&self) -> bool {
+        self.cursor
+            .as_ref()
+            .is_none_or(|(_, values)| values.len() == self.columns.len())
+    }
+
+    /// Whether a cursor value is NULL, which no row compares equal to or greater than.
+    pub(crate) fn has_null_cursor_value(&self) -> bool {
+        self.cursor.as_ref().is_some_and(|(_, values)| {
+            values
+                .iter()
+                .any(|value| value.to_value() == Some(Value::Null))
+        })
+    }
+
+    /// The condition that selects the rows after the cursor, e.g. `a < x OR (a = x AND b < y)`.
+    pub(crate) fn format_condition(
+        &self,
+        bindings: &mut Bindings,
+    ) -> Result<Option<String>, EloquentError> {
+        let Some((direction, values)) = &self.cursor else {
+            return Ok(None);
+        };
+
+        let mut branches = Vec::new();
+
+        for (index, (column, order)) in self.columns.iter().enumerate() {
+            let mut comparisons = Vec::new();
+
+            for (previous, value) in self.columns[..index].iter().zip(values) {
+                comparisons.push(format!(
+                    "{} = {}",
+                    bindings.identifier(&previous.0),
+                    value.to_sql_bound(bindings)?
+                ));
+            }
+
+            let operator = match (order, direction) {
+                (Order::Asc, Direction::Next) | (Order::Desc, Direction::Previous) => ">",
+                (Order::Desc, Direction::Next) | (Order::Asc, Direction::Previous) => "<",
+            };
+
+            comparisons.push(format!(
+                "{} {} {}",
+                bindings.identifier(column),
+                operator,
+                values
+                    .get(index)
+                    .ok_or(EloquentError::InconsistentCursorValues)?
+                    .to_sql_bound(bindings)?
+            ));
+
+            branches.push(match comparisons.len() {
+                1 => comparisons.remove(0),
+                _ => format!("({})", comparisons.join(" AND ")),
+            });
+        }
+
+        Ok(match branches.len() {
+            0 => None,
+            1 => Some(branches.remove(0)),
+            _ => Some(format!("({})", branches.join(" OR "))),
+        })
+    }
+
+    /// The `ORDER BY` columns, reversed when paging to the previous page.
+    pub(crate) fn format_order_by(&self, bindings: &Bindings) -> Vec<String> {
+        let reversed = matches!(self.cursor, Some((Direction::Previous, _)));
+
+        self.columns
+            .iter()
+            .map(|(column, order)| {
+                let order = match (order, reversed) {
+                    (Order::Asc, false) | (Order::Desc, true) => Order::Asc,
+                    (Order::Desc, false) | (Order::Asc, true) => Order::Desc,
+                };
+
+                format!("{} {}", bindings.identifier(column), order)
+            })
+            .collect()
+    }
+}
+
+impl Cursor {
+    /// A cursor to the rows after the given keyset values, usually those of the last row of a page.
+    pub fn next(values: Vec<Value>) -> Self {
+        Self {
+            direction: Direction::Next,
+            values,
+        }
+    }
+
+    /// A cursor to the rows before the given keyset values, usually those of the first row of a page.
+    pub fn previous(values: Vec<Value>) -> Self {
+        Self {
+            direction: Direction::Previous,
+            values,
+        }
+    }
+
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// Encode the cursor as a URL-safe token.
+    pub fn encode(&self) -> String {
+        let mut payload = String::from(match self.direction {
+            Direction::Next => "n",
+            Direction::Previous => "p",
+        });
+
+        for value in &self.values {
+            let (tag, content) = match value {
+                Value::Null => ('z', String::new()),
+                Value::Bool(value) => ('b', value.to_string()),
+                Value::Int(value) => ('i', value.to_string()),
+                Value::UInt(value) => ('u', value.to_string()),
+                Value::Float(value) => ('f', value.to_string()),
+                Value::Text(value) => ('t', value.clone()),
+            };
+
+            payload.push_str(&format!("{}{}:{}", tag, content.len(), content));
+        }
+
+        base64_encode(payload.as_bytes())
+    }
+
+    /// Decode a token created with [`Cursor::encode`].
+    pub fn decode(token: &str) -> Result<Self, EloquentError> {
+        let invalid = || EloquentError::InvalidCursor(token.to_string());
+
+        let payload = base64_decode(token)
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or_else(invalid)?;
+
+        let direction = match payload.get(..1) {
+            Some("n") => Direction::Next,
+            Some("p") => Direction::Previous,
+            _ => return Err(invalid()),
+        };
+
+        let mut values = Vec::new();
+        let mut rest = &payload[1..];
+
+        while !rest.is_empty() {
+            let tag = rest.get(..1).ok_or_else(invalid)?;
+            let (length, remainder) = rest[1..].split_once(':').ok_or_else(invalid)?;
+            let length: usize = length.parse().map_err(|_| invalid())?;
+            let content = remainder.get(..length).ok_or_else(invalid)?;
+
+            let value = match tag {
+                "z" if content.is_empty() => Value::Null,
+                "b" => Value::Bool(content.parse().map_err(|_| invalid())?),
+                "i" => Value::Int(content.parse().map_err(|_| invalid())?),
+                "u" => Value::UInt(content.parse().map_err(|_| invalid())?),
+                "f" => Value::Float(content.parse().map_err(|_| invalid())?),
+                "t" => Value::Text(content.to_string()),
+                _ => return Err(invalid()),
+            };
+
+            values.push(value);
+            rest = &remainder[length..];
+        }
+
+        Ok(Self { direction, values })
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+fn base64_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::new();
+
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |buffer, (index, byte)| {
+                buffer | (u32::from(*byte) << (16 - 8 * index))
+            });
+
+        for index in 0..=chunk.len() {
+            encoded.push(BASE64_ALPHABET[(buffer >> (18 - 6 * index) & 0x3f) as usize] as char);
+        }
+    }
+
+    encoded
+}
+
+fn base64_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    for chunk in encoded.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+
+        let mut buffer = 0u32;
+
+        for (index, character) in chunk.iter().enumerate() {
+            let sextet = BASE64_ALPHABET.iter().position(|c| c == character)?;
+
+            buffer |= (sextet as u32) << (18 - 6 * index);
+        }
+
+        for index in 0..chunk.len() - 1 {
+            bytes.push((buffer >> (16 - 8 * index)) as u8);
+        }
+    }
+
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{error::EloquentError, Cursor, Keyset, QueryBuilder, Value};
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = Cursor::next(vec![
+            Value::Null,
+            Value::Bool(true),
+            Value::Int(-7),
+            Value::UInt(7),
+            Value::Float(1.5),
+            Value::Text("a:b 1ü".to_string()),
+        ]);
+
+        let token = cursor.encode();
+
+        assert!(token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(Cursor::decode(&token).unwrap(), cursor);
+    }
+
+    #[test]
+    fn test_invalid_cursor() {
+        match Cursor::decode("not a cursor") {
+            Err(EloquentError::InvalidCursor(token)) => assert_eq!(token, "not a cursor"),
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_short_cursor_without_validation() {
+        let result = QueryBuilder::new()
+            .skip_validation()
+            .table("flights")
+            .keyset(
+                Keyset::new(10)
+                    .order_by_asc("departure_time")
+                    .order_by_asc("id")
+                    .cursor(Cursor::next(vec![Value::Int(1)])),
+            )
+            .sql();
+
+        match result {
+            Err(EloquentError::InconsistentCursorValues) => {}
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+}
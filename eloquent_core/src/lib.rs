@@ -2,55 +2,106 @@
 //!
 //! The core library for building SQL queries. This library is used by the [Eloquent](https://crates.io/crates/eloquent) library to build SQL queries.
 
-use compiler::{build_statement, build_substatement};
+use compiler::{
+    build_statement, build_substatement, compile_statement, compile_subselect, compile_substatement,
+};
 use error::EloquentError;
-use std::fmt::Display;
+use std::{fmt::Display, sync::Arc};
 
 mod builders;
+mod case;
 mod checks;
+mod column;
 mod compiler;
 mod compilers;
+mod count_query;
+mod dialects;
 /// The error module that contains all the possible errors that can occur while building a query.
 pub mod error;
+/// The execution module that runs compiled queries with their bound values on a database.
+#[cfg(any(feature = "rusqlite", feature = "sqlx", feature = "tokio-postgres"))]
+pub mod execution;
+mod executor;
+mod index_definition;
+mod join_clause;
+mod keyset;
+/// The migrations module that applies and reverts ordered schema changes.
+#[cfg(feature = "migrations")]
+pub mod migrations;
 mod queries;
 mod query_builder;
+mod schema;
+mod schema_builder;
 mod subqueries;
 mod subquery_builder;
+mod table;
+mod table_definition;
 mod to_sql;
 mod validator;
-
+mod window;
+
+pub use case::Case;
+pub use column::{Column, ColumnValue, Comparable, Field};
+pub use count_query::CountQuery;
+pub use dialects::*;
+pub use executor::{Executor, RecordingExecutor};
+pub use index_definition::IndexDefinition;
+pub use join_clause::JoinClause;
+pub use keyset::{Cursor, Direction, Keyset};
+pub use schema::{ColumnType, Schema, TableSchema};
+pub use schema_builder::SchemaBuilder;
+pub use table::Table;
+pub use table_definition::{AlterTable, ColumnDefinition, TableDefinition};
 pub use to_sql::*;
+pub use window::{FrameBound, Window};
+use window::{Over, WindowFunction};
 
 /// The main builder struct that holds all the query building information.
 pub struct QueryBuilder {
+    ctes: Vec<Cte>,
     table: Option<String>,
+    table_alias: Option<String>,
     selects: Vec<Select>,
     inserts: Vec<Insert>,
+    insert_using: Option<InsertUsing>,
+    upsert: Option<Upsert>,
     updates: Vec<Update>,
+    missing_primary_key: Option<String>,
     delete: bool,
+    returning: Vec<String>,
     conditions: Vec<Condition>,
     closures: Vec<(Logic, Vec<Condition>)>,
     joins: Vec<Join>,
-    havings: Vec<Having>,
+    havings: Vec<Condition>,
+    having_closures: Vec<(Logic, Vec<Condition>)>,
     group_by: Vec<String>,
     order_by: Vec<OrderColumn>,
     limit: Option<u64>,
     offset: Option<u64>,
+    page_out_of_range: Option<(u64, u64)>,
+    set_operations: Vec<SetOperation>,
     enable_checks: bool,
-    paginate: Option<Paginate>,
+    paginate: Option<Keyset>,
+    aggregate: Option<Aggregate>,
+    dialect: Box<dyn Dialect>,
+    schema: Option<Arc<Schema>>,
 }
 
 /// The subquery builder struct that holds all the subquery building information.
 pub struct SubqueryBuilder {
+    ctes: Vec<Cte>,
     table: Option<String>,
+    table_alias: Option<String>,
     selects: Vec<Select>,
     conditions: Vec<Condition>,
     joins: Vec<Join>,
-    havings: Vec<Having>,
+    havings: Vec<Condition>,
+    having_closures: Vec<(Logic, Vec<Condition>)>,
     group_by: Vec<String>,
     order_by: Vec<OrderColumn>,
     limit: Option<u64>,
     offset: Option<u64>,
+    set_operations: Vec<SetOperation>,
 }
 
 pub trait ToSql {
@@ -59,6 +110,19 @@ pub trait ToSql {
     fn is_subquery(&self) -> bool {
         false
     }
+
+    /// The typed value that is bound as a parameter, `None` keeps the value inline.
+    fn to_value(&self) -> Option<Value> {
+        None
+    }
+
+    /// Compile the value into the statement, binding it as a parameter where possible.
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        match self.to_value() {
+            Some(value) => bindings.push(value),
+            None => self.to_sql(),
+        }
+    }
 }
 
 pub trait Columnable {
@@ -67,18 +131,39 @@ pub trait Columnable {
 
 pub trait Selectable {
     fn to_select_column(&self) -> String;
+
+    fn to_select_expression(self) -> Option<Box<dyn ToSql>>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
-pub(crate) trait PerformChecks {
-    fn check(builder: &QueryBuilder) -> Result<(), EloquentError>;
+pub trait Combinable {
+    /// Compile the query as one of the branches of a set operation.
+    fn to_combined_sql(&self, bindings: &mut Bindings) -> Result<String, EloquentError>;
+
+    /// The number of selected columns, `None` when all columns are selected.
+    fn selected_column_count(&self) -> Option<usize>;
+
+    /// Whether the query is a `SELECT`, only those can be combined.
+    fn is_select(&self) -> bool;
+
+    /// The clause that cannot be compiled inside a branch, e.g. a `WITH` or an `ORDER BY` that
+    /// would apply to the whole combined result.
+    fn ordering_clause(&self) -> Option<String>;
+}
+
+pub(crate) trait PerformChecks<B = QueryBuilder> {
+    fn check(builder: &B) -> Result<(), EloquentError>;
 }
 
-#[allow(clippy::borrowed_box)]
 pub(crate) trait SqlBuilder {
-    fn build<'a>(
-        builder: &'a QueryBuilder,
+    fn build(
+        builder: &QueryBuilder,
         sql: &mut String,
-        params: &mut Vec<&'a Box<dyn ToSql + 'static>>,
+        bindings: &mut Bindings,
     ) -> Result<String, EloquentError>;
 }
 
@@ -95,12 +180,29 @@ struct Condition {
     operator: Operator,
     logic: Logic,
     values: Vec<Box<dyn ToSql>>,
+    function: Option<Function>,
+}
+
+struct Cte {
+    name: String,
+    columns: Vec<String>,
+    query: SubqueryBuilder,
+    recursive: Option<SubqueryBuilder>,
 }
 
 struct Select {
     column: String,
     function: Option<Function>,
     alias: Option<String>,
+    expression: Option<Box<dyn ToSql>>,
+    over: Option<Over>,
+}
+
+struct ColumnReference(String);
+
+struct RawExpression {
+    sql: String,
+    values: Vec<Box<dyn ToSql>>,
 }
 
 struct Insert {
@@ -108,19 +210,27 @@ struct Insert {
     values: Vec<Box<dyn ToSql>>,
 }
 
+struct InsertUsing {
+    columns: Vec<String>,
+    query: SubqueryBuilder,
+}
+
+struct Upsert {
+    target: Vec<String>,
+    updates: Vec<String>,
+    conditions: Vec<Condition>,
+    closures: Vec<(Logic, Vec<Condition>)>,
+}
+
 struct Update {
     column: String,
     value: Box<dyn ToSql>,
 }
 
-#[derive(PartialEq)]
 struct OrderColumn {
     column: String,
     order: Order,
-}
-
-struct Having {
-    conditions: Vec<Condition>,
+    expression: Option<Box<dyn ToSql>>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -141,6 +251,9 @@ enum Operator {
     Year,
     Month,
     Day,
+    Exists,
+    NotExists,
+    Unsupported(String),
 }
 
 #[derive(Debug, PartialEq)]
@@ -149,8 +262,9 @@ enum Logic {
     Or,
 }
 
-#[derive(Debug, PartialEq, Eq, Hash)]
-enum Function {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// The aggregate functions that can be applied to a column.
+pub enum Function {
     Count,
     Sum,
     Avg,
@@ -159,11 +273,23 @@ enum Function {
     Distinct,
 }
 
+/// The aggregate a query is turned into with `into_count`, `into_exists` or `into_aggregate`.
+pub(crate) enum Aggregate {
+    Count,
+    Exists,
+    Function(Function, String),
+}
+
 struct Join {
-    table: String,
-    left_hand: String,
+    source: JoinSource,
+    alias: Option<String>,
     join_type: JoinType,
-    right_hand: String,
+    clause: JoinClause,
+}
+
+enum JoinSource {
+    Table(String),
+    Subquery(Box<SubqueryBuilder>),
 }
 
 enum JoinType {
@@ -171,6 +297,22 @@ enum JoinType {
     Left,
     Right,
     Full,
+    Cross,
+    Natural,
+    Lateral,
+    LeftLateral,
+}
+
+struct SetOperation {
+    operator: SetOperator,
+    query: Box<dyn Combinable>,
+}
+
+enum SetOperator {
+    Union,
+    UnionAll,
+    Intersect,
+    Except,
 }
 
 #[derive(Debug, PartialEq)]
@@ -179,36 +321,48 @@ pub(crate) enum Order {
     Desc,
 }
 
-pub(crate) struct Paginate {
-    column: String,
-    last_id: Option<Box<dyn ToSql>>,
-    per_page: u64,
-}
-
 impl Select {
-    fn format_column_name(&self) -> String {
-        let column = match &self.function {
-            Some(function) => match function {
-                Function::Distinct => format!("{} {}", function, self.column),
-                _ => format!("{}({})", function, self.column),
+    fn format_column_name(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        let column = match &self.expression {
+            Some(expression) => expression.to_sql_bound(bindings)?,
+            None => bindings.identifier(&self.column),
+        };
+
+        // DISTINCT is a select modifier, it cannot be evaluated over a window
+        if let Some(Over {
+            function: WindowFunction::Aggregate(Function::Distinct),
+            ..
+        }) = &self.over
+        {
+            return Err(EloquentError::NotAnAggregateFunction(
+                Function::Distinct.to_string(),
+            ));
+        }
+
+        let column = match (&self.over, &self.function) {
+            (Some(over), _) => over.format(&column, bindings),
+            (None, Some(function)) => match function {
+                Function::Distinct => format!("{} {}", function, column),
+                _ => format!("{}({})", function, column),
             },
-            None => self.column.clone(),
+            (None, None) => column,
         };
 
         if let Some(alias) = &self.alias {
-            format!("{} AS {}", column, alias)
+            Ok(format!("{} AS {}", column, bindings.identifier(alias)))
         } else {
-            column
+            Ok(column)
         }
     }
 
     fn format_column_name_without_alias(&self) -> String {
-        match &self.function {
-            Some(function) => match function {
+        match (&self.over, &self.function) {
+            (Some(over), _) => over.format(&self.column, &Bindings::inline(&Generic)),
+            (None, Some(function)) => match function {
                 Function::Distinct => format!("{} {}", function, self.column),
                 _ => format!("{}({})", function, self.column),
             },
-            None => self.column.clone(),
+            (None, None) => self.column.clone(),
         }
     }
 }
@@ -219,10 +373,35 @@ impl Selectable for &str {
     }
 }
 
+impl Selectable for String {
+    fn to_select_column(&self) -> String {
+        self.clone()
+    }
+}
+
+impl Selectable for &String {
+    fn to_select_column(&self) -> String {
+        self.to_string()
+    }
+}
+
 impl Selectable for SubqueryBuilder {
     fn to_select_column(&self) -> String {
         self.to_sql().unwrap()
     }
+
+    fn to_select_expression(self) -> Option<Box<dyn ToSql>> {
+        Some(Box::new(self))
+    }
+}
+
+impl RawExpression {
+    fn new(sql: &str, values: Vec<Box<dyn ToSql>>) -> Self {
+        RawExpression {
+            sql: sql.to_string(),
+            values,
+        }
+    }
 }
 
 impl Condition {
@@ -232,14 +411,24 @@ impl Condition {
             operator,
             logic,
             values,
+            function: None,
         }
     }
+
+    /// Whether the condition compares a column, an `EXISTS` condition only has a subquery.
+    fn has_field(&self) -> bool {
+        !matches!(self.operator, Operator::Exists | Operator::NotExists)
+    }
 }
 
 impl ToSql for &str {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(format!("'{}'", self.replace('\'', "''")))
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::Text(self.to_string()))
+    }
 }
 
 impl ToSql for String {
@@ -247,6 +436,10 @@ impl ToSql for String {
     fn to_sql(&self) -> Result<String, EloquentError> {
         self.as_str().to_sql()
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::Text(self.clone()))
+    }
 }
 
 impl ToSql for &String {
@@ -254,48 +447,81 @@ impl ToSql for &String {
     fn to_sql(&self) -> Result<String, EloquentError> {
         self.as_str().to_sql()
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::Text(self.to_string()))
+    }
 }
 
 impl ToSql for i32 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::Int(i64::from(*self)))
+    }
 }
 
 impl ToSql for i64 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::Int(*self))
+    }
 }
 
 impl ToSql for u32 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::UInt(u64::from(*self)))
+    }
 }
 
 impl ToSql for u64 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::UInt(*self))
+    }
 }
 
 impl ToSql for f32 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_value(&self) -> Option<Value> {
+        // widen through the decimal representation so that `0.1f32` is bound as `0.1`
+        self.to_string().parse().ok().map(Value::Float)
+    }
 }
 
 impl ToSql for f64 {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::Float(*self))
+    }
 }
 
 impl ToSql for bool {
     fn to_sql(&self) -> Result<String, EloquentError> {
         Ok(self.to_string())
     }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(Value::Bool(*self))
+    }
 }
 
 #[cfg(feature = "nullable-types")]
@@ -309,12 +535,23 @@ where
             Some(value) => value.to_sql(),
         }
     }
+
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        match self {
+            None => bindings.push(Value::Null),
+            Some(value) => value.to_sql_bound(bindings),
+        }
+    }
 }
 
 impl ToSql for QueryBuilder {
     fn to_sql(&self) -> Result<String, EloquentError> {
         build_statement(self)
     }
+
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        compile_statement(self, bindings)
+    }
 }
 
 impl ToSql for SubqueryBuilder {
@@ -325,6 +562,106 @@ impl ToSql for SubqueryBuilder {
     fn is_subquery(&self) -> bool {
         true
     }
+
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        compile_substatement(self, bindings)
+    }
+}
+
+impl ToSql for ColumnReference {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        Ok(self.0.clone())
+    }
+
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        Ok(bindings.identifier(&self.0))
+    }
+}
+
+impl ToSql for RawExpression {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        self.to_sql_bound(&mut Bindings::inline(&Generic))
+    }
+
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        let mut parts = self.sql.split('?');
+        let mut sql = parts.next().unwrap_or_default().to_string();
+
+        let mut values = self.values.iter();
+
+        for part in parts {
+            let value = values.next().ok_or(EloquentError::MissingPlaceholders)?;
+
+            sql.push_str(&value.to_sql_bound(bindings)?);
+            sql.push_str(part);
+        }
+
+        Ok(sql)
+    }
+}
+
+impl Combinable for QueryBuilder {
+    fn to_combined_sql(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        compile_statement(self, bindings)
+    }
+
+    fn selected_column_count(&self) -> Option<usize> {
+        match self.selects.len() {
+            0 => None,
+            count => Some(count),
+        }
+    }
+
+    fn is_select(&self) -> bool {
+        self.get_action() == Action::Select
+    }
+
+    fn ordering_clause(&self) -> Option<String> {
+        if !self.ctes.is_empty() {
+            Some("WITH".to_string())
+        } else if !self.order_by.is_empty() {
+            Some("ORDER BY".to_string())
+        } else if self.limit.is_some() {
+            Some("LIMIT".to_string())
+        } else if self.offset.is_some() {
+            Some("OFFSET".to_string())
+        } else if self.paginate.is_some() {
+            Some("PAGINATE".to_string())
+        } else {
+            None
+        }
+    }
+}
+
+impl Combinable for SubqueryBuilder {
+    fn to_combined_sql(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        compile_subselect(self, bindings)
+    }
+
+    fn selected_column_count(&self) -> Option<usize> {
+        match self.selects.len() {
+            0 => None,
+            count => Some(count),
+        }
+    }
+
+    fn is_select(&self) -> bool {
+        true
+    }
+
+    fn ordering_clause(&self) -> Option<String> {
+        if !self.ctes.is_empty() {
+            Some("WITH".to_string())
+        } else if !self.order_by.is_empty() {
+            Some("ORDER BY".to_string())
+        } else if self.limit.is_some() {
+            Some("LIMIT".to_string())
+        } else if self.offset.is_some() {
+            Some("OFFSET".to_string())
+        } else {
+            None
+        }
+    }
 }
 
 impl Columnable for &str {
@@ -339,6 +676,21 @@ impl Columnable for Vec<&str> {
     }
 }
 
+impl Operator {
+    /// Parse a comparison operator such as `>=` or `!=`.
+    fn from_comparison(operator: &str) -> Option<Self> {
+        match operator {
+            "=" => Some(Operator::Equal),
+            "!=" | "<>" => Some(Operator::NotEqual),
+            ">" => Some(Operator::GreaterThan),
+            ">=" => Some(Operator::GreaterThanOrEqual),
+            "<" => Some(Operator::LessThan),
+            "<=" => Some(Operator::LessThanOrEqual),
+            _ => None,
+        }
+    }
+}
+
 impl Display for Operator {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let operator = match self {
@@ -358,6 +710,9 @@ impl Display for Operator {
             Operator::Year => "YEAR",
             Operator::Month => "MONTH",
             Operator::Day => "DAY",
+            Operator::Exists => "EXISTS",
+            Operator::NotExists => "NOT EXISTS",
+            Operator::Unsupported(operator) => operator,
         };
 
         write!(f, "{}", operator)
@@ -390,6 +745,49 @@ impl Display for Function {
     }
 }
 
+impl Display for Aggregate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Aggregate::Count => write!(f, "COUNT"),
+            Aggregate::Exists => write!(f, "EXISTS"),
+            Aggregate::Function(function, _) => write!(f, "{}", function),
+        }
+    }
+}
+
+impl Display for SetOperator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let operator = match self {
+            SetOperator::Union => "UNION",
+            SetOperator::UnionAll => "UNION ALL",
+            SetOperator::Intersect => "INTERSECT",
+            SetOperator::Except => "EXCEPT",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
+
+impl JoinType {
+    /// The dialect feature the join depends on, if any.
+    fn feature(&self) -> Option<Feature> {
+        match self {
+            JoinType::Full => Some(Feature::FullJoin),
+            JoinType::Natural => Some(Feature::NaturalJoin),
+            JoinType::Lateral | JoinType::LeftLateral => Some(Feature::Lateral),
+            _ => None,
+        }
+    }
+
+    /// Whether the join needs an `ON` clause.
+    fn requires_condition(&self) -> bool {
+        matches!(
+            self,
+            JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full
+        )
+    }
+}
+
 impl Display for JoinType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let join_type = match self {
@@ -397,6 +795,10 @@ impl Display for JoinType {
             JoinType::Left => "LEFT JOIN",
             JoinType::Right => "RIGHT JOIN",
             JoinType::Full => "FULL JOIN",
+            JoinType::Cross => "CROSS JOIN",
+            JoinType::Natural => "NATURAL JOIN",
+            JoinType::Lateral => "CROSS JOIN LATERAL",
+            JoinType::LeftLateral => "LEFT JOIN LATERAL",
         };
 
         write!(f, "{}", join_type)
@@ -404,39 +806,84 @@ impl Display for JoinType {
 }
 
 impl Condition {
-    fn format_sql(&self) -> String {
+    fn format_sql(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        let field = match &self.function {
+            Some(Function::Distinct) => {
+                return Err(EloquentError::NotAnAggregateFunction(
+                    Function::Distinct.to_string(),
+                ))
+            }
+            Some(function) => format!("{}({})", function, bindings.identifier(&self.field)),
+            None => bindings.identifier(&self.field),
+        };
+
+        if let Operator::Unsupported(operator) = &self.operator {
+            return Err(EloquentError::UnsupportedOperator(operator.clone()));
+        }
+
+        if matches!(self.operator, Operator::IsNull | Operator::IsNotNull) {
+            return Ok(format!("{} {}", field, self.operator));
+        }
+
+        if !self.has_field() {
+            let subquery = self
+                .values
+                .iter()
+                .map(|v| v.to_sql_bound(bindings))
+                .collect::<Result<String, EloquentError>>()?;
+
+            return Ok(format!("{} {}", self.operator, subquery));
+        }
+
         let values = self
             .values
             .iter()
-            .map(|v| v.to_sql().unwrap())
-            .collect::<Vec<String>>()
-            .join(", ");
+            .map(|v| v.to_sql_bound(bindings))
+            .collect::<Result<Vec<String>, EloquentError>>()?;
 
-        match self.operator {
+        let sql = match self.operator {
             Operator::Between => format!(
                 "{} {} {} AND {}",
-                self.field,
+                field,
                 self.operator,
-                values.split(", ").next().unwrap(),
-                values.split(", ").last().unwrap()
+                values.first().unwrap(),
+                values.last().unwrap()
             ),
             Operator::In | Operator::NotIn => {
+                let values = values.join(", ");
+
                 if self.values.iter().any(|v| v.is_subquery()) {
                     // subquery already contains parentheses so we don't need to add them
-                    format!("{} {} {}", self.field, self.operator, values)
+                    format!("{} {} {}", field, self.operator, values)
                 } else {
-                    format!("{} {} ({})", self.field, self.operator, values)
+                    format!("{} {} ({})", field, self.operator, values)
                 }
             }
-            Operator::IsNull | Operator::IsNotNull => format!("{} {}", self.field, self.operator),
-            Operator::Date | Operator::Year | Operator::Month | Operator::Day => {
-                format!("{}({}) = {}", self.operator, self.field, values)
-            }
-            _ => format!("{} {} {}", self.field, self.operator, values),
-        }
+            Operator::NotEqual => format!(
+                "{} {} {}",
+                field,
+                bindings.dialect().not_equal_operator(),
+                values.join(", ")
+            ),
+            Operator::Date => date_condition(bindings, DatePart::Date, &field, &values),
+            Operator::Year => date_condition(bindings, DatePart::Year, &field, &values),
+            Operator::Month => date_condition(bindings, DatePart::Month, &field, &values),
+            Operator::Day => date_condition(bindings, DatePart::Day, &field, &values),
+            _ => format!("{} {} {}", field, self.operator, values.join(", ")),
+        };
+
+        Ok(sql)
     }
 }
 
+fn date_condition(bindings: &Bindings, part: DatePart, field: &str, values: &[String]) -> String {
+    format!(
+        "{} = {}",
+        bindings.dialect().date_function(part, field),
+        values.join(", ")
+    )
+}
+
 #[macro_export]
 macro_rules! eloquent_sql_row {
     ($($key:expr => $value:expr),* $(,)?) => {
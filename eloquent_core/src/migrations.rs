@@ -0,0 +1,629 @@
+//! Ordered schema migrations with up and down steps, tracked in a table of the database.
+//!
+//! Every migration is a pair of closures that build the statements with a [`SchemaBuilder`].
+//! Applied migrations are recorded in a tracking table together with the batch they were applied
+//! in, so the last batch can be rolled back again.
+//!
+//! Every migration is applied or reverted in a transaction together with its change to the
+//! tracking table, so a failing statement leaves no partial changes behind. MySQL commits DDL
+//! statements implicitly, and `CREATE INDEX CONCURRENTLY` cannot run in a transaction, so those
+//! migrations run without one and can be left partially applied when a statement fails.
+//!
+//! ```
+//! use eloquent_core::{migrations::Migrator, ColumnDefinition, ColumnType};
+//!
+//! let migrator = Migrator::new().migration(
+//!     "2024_01_01_create_flights",
+//!     |schema| {
+//!         schema.create_table("flights", |table| {
+//!             table.column(ColumnDefinition::new("id", ColumnType::Integer).primary_key())
+//!         })
+//!     },
+//!     |schema| schema.drop_table("flights"),
+//! );
+//!
+//! assert_eq!(
+//!     migrator.plan().unwrap(),
+//!     vec![
+//!         "CREATE TABLE IF NOT EXISTS migrations (migration VARCHAR(255) NOT NULL PRIMARY KEY, batch BIGINT NOT NULL)",
+//!         "CREATE TABLE flights (id INTEGER NOT NULL PRIMARY KEY)",
+//!         "INSERT INTO migrations (migration, batch) VALUES ('2024_01_01_create_flights', 1)",
+//!     ]
+//! );
+//! ```
+
+use crate::{
+    error::EloquentError, ColumnDefinition, ColumnType, Dialect, Executor, Feature, Generic,
+    QueryBuilder, SchemaBuilder, Value,
+};
+use std::collections::HashSet;
+
+type Step = Box<dyn Fn(SchemaBuilder) -> SchemaBuilder>;
+
+struct Migration {
+    name: String,
+    up: Step,
+    down: Step,
+}
+
+/// A migration that is recorded in the tracking table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedMigration {
+    name: String,
+    batch: u64,
+}
+
+/// The ordered list of migrations of a database.
+pub struct Migrator {
+    table: String,
+    migrations: Vec<Migration>,
+    dialect: Box<dyn Fn() -> Box<dyn Dialect>>,
+}
+
+impl Migrator {
+    /// Create a new migrator, tracking the applied migrations in the `migrations` table.
+    pub fn new() -> Self {
+        Self {
+            table: String::from("migrations"),
+            migrations: Vec::new(),
+            dialect: Box::new(|| Box::new(Generic)),
+        }
+    }
+
+    /// Track the applied migrations in another table.
+    pub fn table(mut self, table: &str) -> Self {
+        self.table = table.to_string();
+
+        self
+    }
+
+    /// Set the SQL dialect the migrations are compiled to.
+    pub fn dialect<D>(mut self, dialect: D) -> Self
+    where
+        D: Dialect + Copy + 'static,
+    {
+        self.dialect = Box::new(move || Box::new(dialect));
+
+        self
+    }
+
+    /// Add a migration, which is applied after the migrations that are added before it.
+    pub fn migration<U, D>(mut self, name: &str, up: U, down: D) -> Self
+    where
+        U: Fn(SchemaBuilder) -> SchemaBuilder + 'static,
+        D: Fn(SchemaBuilder) -> SchemaBuilder + 'static,
+    {
+        self.migrations.push(Migration {
+            name: name.to_string(),
+            up: Box::new(up),
+            down: Box::new(down),
+        });
+
+        self
+    }
+
+    /// The statement that creates the tracking table when it does not exist yet.
+    ///
+    /// Dialects without `IF NOT EXISTS` create the table unconditionally here, the migrator guards
+    /// the statement with a check of the dialect instead, e.g. `IF OBJECT_ID(...) IS NULL`.
+    pub fn tracking_table(&self) -> SchemaBuilder {
+        let if_not_exists = (self.dialect)().supports(Feature::IfNotExists);
+
+        self.schema_builder()
+            .create_table(&self.table, |table| {
+                let table = table
+                    .column(
+                        ColumnDefinition::new("migration", ColumnType::Varchar(255)).primary_key(),
+                    )
+                    .column(ColumnDefinition::new("batch", ColumnType::BigInteger));
+
+                match if_not_exists {
+                    true => table.if_not_exists(),
+                    false => table,
+                }
+            })
+            .skip_validation()
+    }
+
+    /// The statements that migrate an empty database, including the tracking table.
+    pub fn plan(&self) -> Result<Vec<String>, EloquentError> {
+        self.check_names()?;
+
+        let mut statements = vec![self.create_tracking_table()?];
+
+        for migration in &self.migrations {
+            statements.extend(self.compile(&migration.up)?);
+            statements.push(self.record(&migration.name, 1).sql()?);
+        }
+
+        Ok(statements)
+    }
+
+    /// The migrations that are recorded in the tracking table.
+    pub fn applied(
+        &self,
+        executor: &mut impl Executor,
+    ) -> Result<Vec<AppliedMigration>, EloquentError> {
+        executor.execute(&self.create_tracking_table()?, &[])?;
+
+        self.query_builder()
+            .table(&self.table)
+            .select(vec!["migration", "batch"])
+            .order_by_asc("batch")
+            .fetch(executor)?
+            .into_iter()
+            .map(|row| match row.as_slice() {
+                [Value::Text(name), Value::Int(batch)] => Ok(AppliedMigration {
+                    name: name.clone(),
+                    batch: u64::try_from(*batch)
+                        .map_err(|error| EloquentError::ExecutionFailed(error.to_string()))?,
+                }),
+                [Value::Text(name), Value::UInt(batch)] => Ok(AppliedMigration {
+                    name: name.clone(),
+                    batch: *batch,
+                }),
+                _ => Err(EloquentError::ExecutionFailed(format!(
+                    "Invalid row in the tracking table '{}'",
+                    self.table
+                ))),
+            })
+            .collect()
+    }
+
+    /// Apply the pending migrations in a new batch, returns the names of the applied migrations.
+    pub fn run(&self, executor: &mut impl Executor) -> Result<Vec<String>, EloquentError> {
+        self.check_names()?;
+
+        let applied = self.applied(executor)?;
+
+        for record in &applied {
+            if !self.migrations.iter().any(|m| m.name == record.name) {
+                return Err(EloquentError::UnknownMigration(record.name.clone()));
+            }
+        }
+
+        let batch = applied.iter().map(|record| record.batch).max().unwrap_or(0) + 1;
+
+        let pending = self
+            .migrations
+            .iter()
+            .filter(|migration| !applied.iter().any(|record| record.name == migration.name))
+            .map(|migration| Ok((migration, self.compile(&migration.up)?)))
+            .collect::<Result<Vec<_>, EloquentError>>()?;
+
+        let mut names = vec![];
+
+        for (migration, statements) in pending {
+            self.apply(
+                executor,
+                &migration.up,
+                statements,
+                self.record(&migration.name, batch),
+            )?;
+
+            names.push(migration.name.clone());
+        }
+
+        Ok(names)
+    }
+
+    /// Revert the migrations of the last batch in reverse order, returns the names of the reverted
+    /// migrations.
+    pub fn rollback(&self, executor: &mut impl Executor) -> Result<Vec<String>, EloquentError> {
+        self.check_names()?;
+
+        let applied = self.applied(executor)?;
+
+        let Some(batch) = applied.iter().map(|record| record.batch).max() else {
+            return Ok(vec![]);
+        };
+
+        let mut reverted = vec![];
+
+        for record in applied.iter().filter(|record| record.batch == batch) {
+            let migration = self
+                .migrations
+                .iter()
+                .position(|migration| migration.name == record.name)
+                .ok_or_else(|| EloquentError::UnknownMigration(record.name.clone()))?;
+
+            reverted.push(migration);
+        }
+
+        reverted.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut names = vec![];
+
+        for index in reverted {
+            let migration = &self.migrations[index];
+
+            let forget = self
+                .query_builder()
+                .table(&self.table)
+                .r#where("migration", migration.name.clone())
+                .delete();
+
+            self.apply(
+                executor,
+                &migration.down,
+                self.compile(&migration.down)?,
+                forget,
+            )?;
+
+            names.push(migration.name.clone());
+        }
+
+        Ok(names)
+    }
+
+    /// The tracking table statement, guarded by the dialect when it has no `IF NOT EXISTS`.
+    fn create_tracking_table(&self) -> Result<String, EloquentError> {
+        let statement = self.tracking_table().sql()?;
+
+        match (self.dialect)().table_missing_guard(&self.table) {
+            Some(guard) if !(self.dialect)().supports(Feature::IfNotExists) => {
+                Ok(format!("{} {}", guard, statement))
+            }
+            _ => Ok(statement),
+        }
+    }
+
+    fn check_names(&self) -> Result<(), EloquentError> {
+        let mut seen = HashSet::new();
+
+        for migration in &self.migrations {
+            if !seen.insert(&migration.name) {
+                return Err(EloquentError::DuplicatedMigrationNames(
+                    migration.name.clone(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The statements of a step, a step without statements compiles to nothing.
+    fn compile(&self, step: &Step) -> Result<Vec<String>, EloquentError> {
+        let builder = step(self.schema_builder());
+
+        if builder.statements.is_empty() {
+            return Ok(vec![]);
+        }
+
+        builder.to_statements()
+    }
+
+    /// Execute the statements of a step and the change to the tracking table, in a transaction
+    /// when the dialect and the statements allow it.
+    fn apply(
+        &self,
+        executor: &mut impl Executor,
+        step: &Step,
+        statements: Vec<String>,
+        tracking: QueryBuilder,
+    ) -> Result<(), EloquentError> {
+        let dialect = (self.dialect)();
+
+        let transactional = dialect.supports(Feature::TransactionalDdl)
+            && step(self.schema_builder()).is_transactional();
+
+        if transactional {
+            executor.execute(dialect.begin_transaction(), &[])?;
+        }
+
+        let result = statements
+            .iter()
+            .try_for_each(|statement| executor.execute(statement, &[]).map(|_| ()))
+            .and_then(|_| tracking.execute(executor).map(|_| ()));
+
+        match (result, transactional) {
+            (Ok(()), true) => executor
+                .execute(dialect.commit_transaction(), &[])
+                .map(|_| ()),
+            (Err(error), true) => {
+                // the error of the failing statement is more useful than that of the rollback
+                let _ = executor.execute(dialect.rollback_transaction(), &[]);
+
+                Err(error)
+            }
+            (result, false) => result,
+        }
+    }
+
+    fn record(&self, name: &str, batch: u64) -> QueryBuilder {
+        self.query_builder()
+            .table(&self.table)
+            .insert("migration", name.to_string())
+            .insert("batch", batch)
+    }
+
+    fn schema_builder(&self) -> SchemaBuilder {
+        let mut builder = SchemaBuilder::new();
+        builder.dialect = (self.dialect)();
+
+        builder
+    }
+
+    fn query_builder(&self) -> QueryBuilder {
+        let mut builder = QueryBuilder::new().skip_validation();
+        builder.dialect = (self.dialect)();
+
+        builder
+    }
+}
+
+impl AppliedMigration {
+    /// The name of the migration.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The batch the migration was applied in.
+    pub fn batch(&self) -> u64 {
+        self.batch
+    }
+}
+
+impl Default for Migrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Migrator;
+    use crate::{
+        error::EloquentError, ColumnDefinition, ColumnType, Dialect, Generic, MySql, Postgres,
+        SqlServer, Sqlite,
+    };
+    #[cfg(feature = "rusqlite")]
+    use crate::{Executor, Value};
+    #[cfg(feature = "rusqlite")]
+    use rusqlite::Connection;
+
+    fn migrator() -> Migrator {
+        Migrator::new()
+            .dialect(Sqlite)
+            .migration(
+                "2024_01_01_create_flights",
+                |schema| {
+                    schema.create_table("flights", |table| {
+                        table
+                            .column(ColumnDefinition::new("id", ColumnType::Integer).primary_key())
+                            .column(ColumnDefinition::new("origin", ColumnType::Varchar(3)))
+                    })
+                },
+                |schema| schema.drop_table("flights"),
+            )
+            .migration(
+                "2024_01_02_add_gate_to_flights",
+                |schema| {
+                    schema.alter_table("flights", |table| {
+                        table.add_column(
+                            ColumnDefinition::new("gate", ColumnType::Varchar(5)).nullable(),
+                        )
+                    })
+                },
+                |schema| schema.alter_table("flights", |table| table.drop_column("gate")),
+            )
+    }
+
+    #[cfg(feature = "rusqlite")]
+    fn tables(executor: &mut Connection) -> Vec<Value> {
+        executor
+            .query(
+                "SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name",
+                &[],
+            )
+            .unwrap()
+            .into_iter()
+            .flatten()
+            .collect()
+    }
+
+    #[test]
+    #[cfg(feature = "rusqlite")]
+    fn test_run_and_rollback_migrations() {
+        let mut executor = Connection::open_in_memory().unwrap();
+
+        let applied = migrator().run(&mut executor).unwrap();
+
+        assert_eq!(
+            applied,
+            vec![
+                "2024_01_01_create_flights",
+                "2024_01_02_add_gate_to_flights"
+            ]
+        );
+
+        executor
+            .execute(
+                "INSERT INTO flights (id, origin, gate) VALUES (1, 'AMS', 'D7')",
+                [],
+            )
+            .unwrap();
+
+        assert!(migrator().run(&mut executor).unwrap().is_empty());
+
+        let reverted = migrator().rollback(&mut executor).unwrap();
+
+        assert_eq!(
+            reverted,
+            vec![
+                "2024_01_02_add_gate_to_flights",
+                "2024_01_01_create_flights"
+            ]
+        );
+        assert_eq!(
+            tables(&mut executor),
+            vec![Value::Text("migrations".to_string())]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rusqlite")]
+    fn test_migrations_are_applied_in_batches() {
+        let mut executor = Connection::open_in_memory().unwrap();
+
+        let first = Migrator::new().dialect(Sqlite).migration(
+            "2024_01_01_create_flights",
+            |schema| {
+                schema.create_table("flights", |table| {
+                    table.column(ColumnDefinition::new("id", ColumnType::Integer))
+                })
+            },
+            |schema| schema.drop_table("flights"),
+        );
+
+        first.run(&mut executor).unwrap();
+        migrator().run(&mut executor).unwrap();
+
+        let applied = migrator().applied(&mut executor).unwrap();
+
+        assert_eq!(applied[0].name(), "2024_01_01_create_flights");
+        assert_eq!(applied[0].batch(), 1);
+        assert_eq!(applied[1].name(), "2024_01_02_add_gate_to_flights");
+        assert_eq!(applied[1].batch(), 2);
+
+        let reverted = migrator().rollback(&mut executor).unwrap();
+
+        assert_eq!(reverted, vec!["2024_01_02_add_gate_to_flights"]);
+        assert_eq!(
+            tables(&mut executor),
+            vec![
+                Value::Text("flights".to_string()),
+                Value::Text("migrations".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rusqlite")]
+    fn test_unknown_applied_migration() {
+        let mut executor = Connection::open_in_memory().unwrap();
+
+        migrator().run(&mut executor).unwrap();
+
+        let result = Migrator::new().dialect(Sqlite).run(&mut executor);
+
+        match result {
+            Err(EloquentError::UnknownMigration(migration)) => {
+                assert_eq!(migration, "2024_01_01_create_flights");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    fn test_duplicated_migration_names() {
+        let result = migrator()
+            .migration(
+                "2024_01_01_create_flights",
+                |schema| schema,
+                |schema| schema,
+            )
+            .plan();
+
+        match result {
+            Err(EloquentError::DuplicatedMigrationNames(migration)) => {
+                assert_eq!(migration, "2024_01_01_create_flights");
+            }
+            Err(_error) => panic!(),
+            Ok(_value) => panic!(),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "rusqlite")]
+    fn test_failed_migration_is_rolled_back() {
+        let mut executor = Connection::open_in_memory().unwrap();
+
+        migrator().run(&mut executor).unwrap();
+
+        let failing = migrator().migration(
+            "2024_01_03_create_gates",
+            |schema| {
+                schema
+                    .create_table("gates", |table| {
+                        table.column(ColumnDefinition::new("code", ColumnType::Varchar(5)))
+                    })
+                    .create_table("flights", |table| {
+                        table.column(ColumnDefinition::new("id", ColumnType::Integer))
+                    })
+            },
+            |schema| schema.drop_table("gates"),
+        );
+
+        assert!(failing.run(&mut executor).is_err());
+        assert_eq!(
+            tables(&mut executor),
+            vec![
+                Value::Text("flights".to_string()),
+                Value::Text("migrations".to_string())
+            ]
+        );
+        assert_eq!(failing.applied(&mut executor).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_plan_for_every_dialect() {
+        fn plan<D: Dialect + Copy + 'static>(dialect: D) -> Vec<String> {
+            Migrator::new()
+                .dialect(dialect)
+                .migration(
+                    "2024_01_01_create_gates",
+                    |schema| {
+                        schema.create_table("gates", |table| {
+                            table.column(ColumnDefinition::new("code", ColumnType::Varchar(5)))
+                        })
+                    },
+                    |schema| schema.drop_table("gates"),
+                )
+                .plan()
+                .unwrap()
+        }
+
+        assert_eq!(
+            plan(Generic),
+            vec![
+                "CREATE TABLE IF NOT EXISTS migrations (migration VARCHAR(255) NOT NULL PRIMARY KEY, batch BIGINT NOT NULL)",
+                "CREATE TABLE gates (code VARCHAR(5) NOT NULL)",
+                "INSERT INTO migrations (migration, batch) VALUES ('2024_01_01_create_gates', 1)",
+            ]
+        );
+        assert_eq!(
+            plan(Postgres),
+            vec![
+                r#"CREATE TABLE IF NOT EXISTS "migrations" ("migration" VARCHAR(255) NOT NULL PRIMARY KEY, "batch" BIGINT NOT NULL)"#,
+                r#"CREATE TABLE "gates" ("code" VARCHAR(5) NOT NULL)"#,
+                r#"INSERT INTO "migrations" ("migration", "batch") VALUES ('2024_01_01_create_gates', 1)"#,
+            ]
+        );
+        assert_eq!(
+            plan(MySql),
+            vec![
+                "CREATE TABLE IF NOT EXISTS `migrations` (`migration` VARCHAR(255) NOT NULL PRIMARY KEY, `batch` BIGINT NOT NULL)",
+                "CREATE TABLE `gates` (`code` VARCHAR(5) NOT NULL)",
+                "INSERT INTO `migrations` (`migration`, `batch`) VALUES ('2024_01_01_create_gates', 1)",
+            ]
+        );
+        assert_eq!(
+            plan(Sqlite),
+            vec![
+                r#"CREATE TABLE IF NOT EXISTS "migrations" ("migration" VARCHAR(255) NOT NULL PRIMARY KEY, "batch" BIGINT NOT NULL)"#,
+                r#"CREATE TABLE "gates" ("code" VARCHAR(5) NOT NULL)"#,
+                r#"INSERT INTO "migrations" ("migration", "batch") VALUES ('2024_01_01_create_gates', 1)"#,
+            ]
+        );
+        assert_eq!(
+            plan(SqlServer),
+            vec![
+                "IF OBJECT_ID(N'migrations', N'U') IS NULL CREATE TABLE [migrations] ([migration] NVARCHAR(255) NOT NULL PRIMARY KEY, [batch] BIGINT NOT NULL)",
+                "CREATE TABLE [gates] ([code] NVARCHAR(5) NOT NULL)",
+                "INSERT INTO [migrations] ([migration], [batch]) VALUES ('2024_01_01_create_gates', 1)",
+            ]
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Aggregate, Function, QueryBuilder};
+
+impl QueryBuilder {
+    /// Turn the query into a count of its rows, keeping its tables, joins and conditions.
+    ///
+    /// The order, limit, offset and pagination are removed, and a query with `GROUP BY`, `HAVING`,
+    /// `DISTINCT`, an aggregate select or a set operation is counted as a subquery.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["flight_number", "origin_airport"])
+    ///     .join("airports", "flights.origin_airport", "airports.code")
+    ///     .where_closure(|query| query.r#where("airports.country", "NL").or_where("airports.country", "BE"))
+    ///     .order_by_asc("flight_number")
+    ///     .limit(10)
+    ///     .into_count();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT COUNT(*) FROM flights JOIN airports ON flights.origin_airport = airports.code WHERE (airports.country = 'NL' OR airports.country = 'BE')"
+    /// );
+    /// ```
+    pub fn into_count(self) -> Self {
+        self.with_aggregate(Aggregate::Count)
+    }
+
+    /// Turn the query into a check whether it returns any rows.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("origin_airport", "AMS")
+    ///     .order_by_desc("departure_time")
+    ///     .into_exists();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT EXISTS(SELECT * FROM flights WHERE origin_airport = 'AMS')"
+    /// );
+    /// ```
+    pub fn into_exists(self) -> Self {
+        self.with_aggregate(Aggregate::Exists)
+    }
+
+    /// Turn the query into an aggregate of a column over its rows, e.g. the latest `updated_at`.
+    ///
+    /// A query with `GROUP BY`, `HAVING`, `DISTINCT`, an aggregate select or a set operation is
+    /// aggregated as a subquery, so the column has to be selected by the query (or fails with
+    /// `UnknownColumn`) and is referenced without its table qualifier. `Function::Distinct` is not
+    /// an aggregate and fails to compile.
+    ///
+    /// ```
+    /// use eloquent_core::{Function, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_gt("flight_duration", 120)
+    ///     .order_by_asc("flight_number")
+    ///     .into_aggregate(Function::Max, "updated_at");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT MAX(updated_at) FROM flights WHERE flight_duration > 120"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin_airport")
+    ///     .select_count("id", "flight_count")
+    ///     .group_by("origin_airport")
+    ///     .into_aggregate(Function::Avg, "flight_count");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT AVG(flight_count) FROM (SELECT origin_airport, COUNT(id) AS flight_count FROM flights GROUP BY origin_airport) AS aggregate_table"
+    /// );
+    /// ```
+    pub fn into_aggregate(self, function: Function, column: &str) -> Self {
+        self.with_aggregate(Aggregate::Function(function, column.to_string()))
+    }
+
+    fn with_aggregate(mut self, aggregate: Aggregate) -> Self {
+        self.order_by.clear();
+        self.limit = None;
+        self.offset = None;
+        self.paginate = None;
+        self.aggregate = Some(aggregate);
+
+        self
+    }
+}
@@ -1,15 +1,18 @@
-use crate::{Columnable, Condition, Logic, Operator, QueryBuilder, ToSql};
+use crate::{
+    ColumnReference, Columnable, Comparable, Condition, Field, Logic, Operator, QueryBuilder,
+    SubqueryBuilder, ToSql,
+};
 
 impl QueryBuilder {
     fn add_condition(
         mut self,
-        field: &str,
+        field: impl Field,
         operator: Operator,
         logic: Logic,
         values: Vec<Box<dyn ToSql>>,
     ) -> Self {
         self.conditions
-            .push(Condition::new(field, operator, logic, values));
+            .push(Condition::new(&field.field_name(), operator, logic, values));
 
         self
     }
@@ -45,7 +48,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE id = (SELECT MAX(duration_in_min) AS max_duration_in_min FROM flights)"
     /// );
     /// ```
-    pub fn r#where(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn r#where<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Equal, Logic::And, vec![Box::new(value)])
     }
 
@@ -80,7 +86,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE id = (SELECT MAX(duration_in_min) AS max_duration_in_min FROM flights)"
     /// );
     /// ```
-    pub fn where_eq(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_eq<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Equal, Logic::And, vec![Box::new(value)])
     }
 
@@ -99,10 +108,60 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE origin = 'AMS' OR destination = 'FRA'"
     /// );
     /// ```
-    pub fn or_where(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Equal, Logic::Or, vec![Box::new(value)])
     }
 
+    /// Add a where condition comparing two columns to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_column("origin_airport", "destination_airport");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE origin_airport = destination_airport"
+    /// );
+    /// ```
+    pub fn where_column(self, field: impl Field, column: impl Field) -> Self {
+        self.add_condition(
+            field.qualified_field_name(),
+            Operator::Equal,
+            Logic::And,
+            vec![Box::new(ColumnReference(column.qualified_field_name()))],
+        )
+    }
+
+    /// Add an OR where condition comparing two columns to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_null("arrival_time")
+    ///     .or_where_column("arrival_time", "departure_time");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE arrival_time IS NULL OR arrival_time = departure_time"
+    /// );
+    /// ```
+    pub fn or_where_column(self, field: impl Field, column: impl Field) -> Self {
+        self.add_condition(
+            field.qualified_field_name(),
+            Operator::Equal,
+            Logic::Or,
+            vec![Box::new(ColumnReference(column.qualified_field_name()))],
+        )
+    }
+
     /// Add a where not condition to the query.
     ///
     /// ```
@@ -117,7 +176,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE origin != 'AMS'"
     /// );
     /// ```
-    pub fn where_not(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_not<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::NotEqual, Logic::And, vec![Box::new(value)])
     }
 
@@ -136,7 +198,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE origin = 'AMS' OR destination != 'AMS'"
     /// );
     /// ```
-    pub fn or_where_not(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_not<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::NotEqual, Logic::Or, vec![Box::new(value)])
     }
 
@@ -154,7 +219,30 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration > 120"
     /// );
     /// ```
-    pub fn where_gt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    ///
+    /// The value can be a scalar subquery, which may reference the columns of the outer query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let average = SubqueryBuilder::new()
+    ///     .table("tickets")
+    ///     .select_avg("price", "price_avg")
+    ///     .where_column("tickets.event_id", "events.id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("events")
+    ///     .where_gt("price", average);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM events WHERE price > (SELECT AVG(price) AS price_avg FROM tickets WHERE tickets.event_id = events.id)"
+    /// );
+    /// ```
+    pub fn where_gt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThan,
@@ -178,7 +266,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration > 120 OR number_of_passengers > 200"
     /// );
     /// ```
-    pub fn or_where_gt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_gt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThan,
@@ -201,7 +292,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration >= 120"
     /// );
     /// ```
-    pub fn where_gte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_gte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThanOrEqual,
@@ -225,7 +319,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration > 120 OR number_of_passengers >= 200"
     /// );
     /// ```
-    pub fn or_where_gte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_gte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThanOrEqual,
@@ -248,7 +345,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration < 120"
     /// );
     /// ```
-    pub fn where_lt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_lt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::LessThan, Logic::And, vec![Box::new(value)])
     }
 
@@ -267,7 +367,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration < 120 OR number_of_passengers < 200"
     /// );
     /// ```
-    pub fn or_where_lt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_lt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::LessThan, Logic::Or, vec![Box::new(value)])
     }
 
@@ -285,7 +388,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration <= 120"
     /// );
     /// ```
-    pub fn where_lte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_lte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::LessThanOrEqual,
@@ -309,7 +415,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration <= 120 OR number_of_passengers <= 200"
     /// );
     /// ```
-    pub fn or_where_lte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_lte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::LessThanOrEqual,
@@ -332,12 +441,16 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE flight_duration BETWEEN 120 AND 180"
     /// );
     /// ```
-    pub fn where_between(
+    pub fn where_between<V, W>(
         self,
-        field: &str,
-        min: impl ToSql + 'static,
-        max: impl ToSql + 'static,
-    ) -> Self {
+        field: impl Comparable<V> + Comparable<W>,
+        min: V,
+        max: W,
+    ) -> Self
+    where
+        V: ToSql + 'static,
+        W: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::Between,
@@ -361,12 +474,16 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE origin = 'AMS' OR flight_duration BETWEEN 120 AND 180"
     /// );
     /// ```
-    pub fn or_where_between(
+    pub fn or_where_between<V, W>(
         self,
-        field: &str,
-        min: impl ToSql + 'static,
-        max: impl ToSql + 'static,
-    ) -> Self {
+        field: impl Comparable<V> + Comparable<W>,
+        min: V,
+        max: W,
+    ) -> Self
+    where
+        V: ToSql + 'static,
+        W: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::Between,
@@ -389,7 +506,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE airplane_type LIKE 'Airbus%'"
     /// );
     /// ```
-    pub fn where_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_like<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Like, Logic::And, vec![Box::new(value)])
     }
 
@@ -408,7 +528,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE airplane_type LIKE 'Airbus%' OR airplane_type LIKE 'Embraer%'"
     /// );
     /// ```
-    pub fn or_where_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_like<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Like, Logic::Or, vec![Box::new(value)])
     }
 
@@ -444,7 +567,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE id IN (SELECT id FROM flights WHERE duration_in_min > 120)"
     /// );
     /// ```
-    pub fn where_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn where_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -468,7 +594,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE origin_airport IN ('AMS', 'FRA') OR destination_airport IN ('AMS', 'FRA')"
     /// );
     /// ```
-    pub fn or_where_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn or_where_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -509,7 +638,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE id NOT IN (SELECT id FROM flights WHERE duration_in_min > 120)"
     /// );
     /// ```
-    pub fn where_not_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn where_not_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -533,7 +665,10 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE origin_airport NOT IN ('AMS', 'FRA') OR destination_airport NOT IN ('AMS', 'FRA')"
     /// );
     /// ```
-    pub fn or_where_not_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn or_where_not_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -586,7 +721,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE departure_time IS NULL OR arrival_time IS NULL"
     /// );
     /// ```
-    pub fn or_where_null(self, field: &str) -> Self {
+    pub fn or_where_null(self, field: impl Field) -> Self {
         self.add_condition(field, Operator::IsNull, Logic::Or, vec![])
     }
 
@@ -638,7 +773,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE departure_time IS NOT NULL OR arrival_time IS NOT NULL"
     /// );
     /// ```
-    pub fn or_where_not_null(self, field: &str) -> Self {
+    pub fn or_where_not_null(self, field: impl Field) -> Self {
         self.add_condition(field, Operator::IsNotNull, Logic::Or, vec![])
     }
 
@@ -711,7 +846,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE DATE(departure_date) = '2024-10-01'"
     /// );
     /// ```
-    pub fn where_date(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_date(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Date, Logic::And, vec![Box::new(value)])
     }
 
@@ -729,7 +864,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE YEAR(departure_date) = 2024"
     /// );
     /// ```
-    pub fn where_year(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_year(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Year, Logic::And, vec![Box::new(value)])
     }
 
@@ -747,7 +882,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE MONTH(departure_date) = 10"
     /// );
     /// ```
-    pub fn where_month(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_month(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Month, Logic::And, vec![Box::new(value)])
     }
 
@@ -765,9 +900,105 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights WHERE DAY(departure_date) = 10"
     /// );
     /// ```
-    pub fn where_day(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_day(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Day, Logic::And, vec![Box::new(value)])
     }
+
+    /// Add a where EXISTS condition to the query, the subquery may reference the columns of the outer query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let bookings = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .where_column("bookings.flight_id", "flights.id")
+    ///     .where_gt("bookings.seats", 2);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_exists(bookings);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE EXISTS (SELECT * FROM bookings WHERE bookings.flight_id = flights.id AND bookings.seats > 2)"
+    /// );
+    /// ```
+    pub fn where_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::Exists, Logic::And, vec![Box::new(subquery)])
+    }
+
+    /// Add an OR where EXISTS condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let bookings = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .where_column("bookings.flight_id", "flights.id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("status", "cancelled")
+    ///     .or_where_exists(bookings);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE status = 'cancelled' OR EXISTS (SELECT * FROM bookings WHERE bookings.flight_id = flights.id)"
+    /// );
+    /// ```
+    pub fn or_where_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::Exists, Logic::Or, vec![Box::new(subquery)])
+    }
+
+    /// Add a where NOT EXISTS condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let bookings = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .where_column("bookings.flight_id", "flights.id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_not_exists(bookings);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE NOT EXISTS (SELECT * FROM bookings WHERE bookings.flight_id = flights.id)"
+    /// );
+    /// ```
+    pub fn where_not_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition(
+            "",
+            Operator::NotExists,
+            Logic::And,
+            vec![Box::new(subquery)],
+        )
+    }
+
+    /// Add an OR where NOT EXISTS condition to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let bookings = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .where_column("bookings.flight_id", "flights.id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_lt("departure_time", "2024-01-01")
+    ///     .or_where_not_exists(bookings);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights WHERE departure_time < '2024-01-01' OR NOT EXISTS (SELECT * FROM bookings WHERE bookings.flight_id = flights.id)"
+    /// );
+    /// ```
+    pub fn or_where_not_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::NotExists, Logic::Or, vec![Box::new(subquery)])
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,81 @@
+use crate::{Columnable, Cte, QueryBuilder, SubqueryBuilder};
+
+impl QueryBuilder {
+    /// Add a common table expression to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let delayed_flights = SubqueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["id", "origin_airport"])
+    ///     .where_gt("delay_in_min", 30);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .with("delayed_flights", delayed_flights)
+    ///     .table("delayed_flights")
+    ///     .select("origin_airport")
+    ///     .select_count("id", "delayed_count")
+    ///     .group_by("origin_airport");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "WITH delayed_flights AS (SELECT id, origin_airport FROM flights WHERE delay_in_min > 30) SELECT origin_airport, COUNT(id) AS delayed_count FROM delayed_flights GROUP BY origin_airport"
+    /// );
+    /// ```
+    pub fn with(mut self, name: &str, query: SubqueryBuilder) -> Self {
+        self.ctes.push(Cte {
+            name: name.to_string(),
+            columns: Vec::new(),
+            query,
+            recursive: None,
+        });
+
+        self
+    }
+
+    /// Add a recursive common table expression to the query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let anchor = SubqueryBuilder::new()
+    ///     .table("employees")
+    ///     .select(vec!["id", "manager_id"])
+    ///     .where_null("manager_id");
+    ///
+    /// let recursive = SubqueryBuilder::new()
+    ///     .table("employees")
+    ///     .select(vec!["employees.id", "employees.manager_id"])
+    ///     .join("hierarchy", "employees.manager_id", "hierarchy.id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .with_recursive("hierarchy", vec!["id", "manager_id"], anchor, recursive)
+    ///     .table("hierarchy")
+    ///     .select("id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "WITH RECURSIVE hierarchy (id, manager_id) AS (SELECT id, manager_id FROM employees WHERE manager_id IS NULL UNION ALL SELECT employees.id, employees.manager_id FROM employees JOIN hierarchy ON employees.manager_id = hierarchy.id) SELECT id FROM hierarchy"
+    /// );
+    /// ```
+    pub fn with_recursive<T>(
+        mut self,
+        name: &str,
+        columns: T,
+        anchor: SubqueryBuilder,
+        recursive: SubqueryBuilder,
+    ) -> Self
+    where
+        T: Columnable,
+    {
+        self.ctes.push(Cte {
+            name: name.to_string(),
+            columns: columns.to_columns(),
+            query: anchor,
+            recursive: Some(recursive),
+        });
+
+        self
+    }
+}
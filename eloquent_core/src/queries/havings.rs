@@ -1,4 +1,4 @@
-use crate::{Condition, Having, Logic, Operator, QueryBuilder};
+use crate::{Condition, Function, Logic, Operator, QueryBuilder, ToSql};
 
 impl QueryBuilder {
     /// Add a having clause to the query.
@@ -19,8 +19,8 @@ impl QueryBuilder {
     ///     "SELECT flights.origin_airport, AVG(flights.flight_duration) AS avg_duration FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport HAVING avg_duration = 300"
     /// );
     /// ```
-    pub fn having(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::Equal)
+    pub fn having(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::Equal, Logic::And, vec![Box::new(value)])
     }
 
     /// Add a having not clause to the query.
@@ -41,8 +41,13 @@ impl QueryBuilder {
     ///     "SELECT flights.origin_airport, AVG(flights.flight_duration) AS avg_duration FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport HAVING avg_duration != 300"
     /// );
     /// ```
-    pub fn having_not(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::NotEqual)
+    pub fn having_not(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::NotEqual,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
     /// Add a having greater than clause to the query.
@@ -63,8 +68,13 @@ impl QueryBuilder {
     ///     "SELECT flights.origin_airport, AVG(flights.flight_duration) AS avg_duration FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport HAVING avg_duration > 300"
     /// );
     /// ```
-    pub fn having_gt(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::GreaterThan)
+    pub fn having_gt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThan,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
     /// Add a having greater than or equal to clause to the query.
@@ -85,8 +95,13 @@ impl QueryBuilder {
     ///     "SELECT flights.origin_airport, AVG(flights.flight_duration) AS avg_duration FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport HAVING avg_duration >= 300"
     /// );
     /// ```
-    pub fn having_gte(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::GreaterThanOrEqual)
+    pub fn having_gte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThanOrEqual,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
     /// Add a having less than clause to the query.
@@ -107,8 +122,13 @@ impl QueryBuilder {
     ///     "SELECT flights.origin_airport, AVG(flights.flight_duration) AS avg_duration FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport HAVING avg_duration < 300"
     /// );
     /// ```
-    pub fn having_lt(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::LessThan)
+    pub fn having_lt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::LessThan,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
     /// Add a having less than or equal to clause to the query.
@@ -129,8 +149,160 @@ impl QueryBuilder {
     ///     "SELECT flights.origin_airport, AVG(flights.flight_duration) AS avg_duration FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport HAVING avg_duration <= 300"
     /// );
     /// ```
-    pub fn having_lte(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::LessThanOrEqual)
+    pub fn having_lte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::LessThanOrEqual,
+            Logic::And,
+            vec![Box::new(value)],
+        )
+    }
+
+    /// Add an OR having clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having("avg_duration", 300);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration = 300"
+    /// );
+    /// ```
+    pub fn or_having(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::Equal, Logic::Or, vec![Box::new(value)])
+    }
+
+    /// Add an OR having not clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having_not("avg_duration", 300);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration != 300"
+    /// );
+    /// ```
+    pub fn or_having_not(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::NotEqual, Logic::Or, vec![Box::new(value)])
+    }
+
+    /// Add an OR having greater than clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having_gt("avg_duration", 300);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration > 300"
+    /// );
+    /// ```
+    pub fn or_having_gt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThan,
+            Logic::Or,
+            vec![Box::new(value)],
+        )
+    }
+
+    /// Add an OR having greater than or equal to clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having_gte("avg_duration", 300);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration >= 300"
+    /// );
+    /// ```
+    pub fn or_having_gte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThanOrEqual,
+            Logic::Or,
+            vec![Box::new(value)],
+        )
+    }
+
+    /// Add an OR having less than clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having_lt("avg_duration", 300);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration < 300"
+    /// );
+    /// ```
+    pub fn or_having_lt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::LessThan, Logic::Or, vec![Box::new(value)])
+    }
+
+    /// Add an OR having less than or equal to clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having_lte("avg_duration", 300);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration <= 300"
+    /// );
+    /// ```
+    pub fn or_having_lte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::LessThanOrEqual,
+            Logic::Or,
+            vec![Box::new(value)],
+        )
     }
 
     /// Add a having between clause to the query.
@@ -151,29 +323,434 @@ impl QueryBuilder {
     ///     "SELECT flights.origin_airport, AVG(flights.flight_duration) AS avg_duration FROM flights JOIN airports ON flights.origin_airport = airports.code GROUP BY flights.origin_airport HAVING avg_duration BETWEEN 300 AND 500"
     /// );
     /// ```
-    pub fn having_between(mut self, column: &str, value_1: i64, value_2: i64) -> Self {
-        self.havings.push(Having {
-            conditions: vec![Condition {
-                field: column.to_string(),
-                operator: Operator::Between,
-                logic: Logic::And,
-                values: vec![Box::new(value_1), Box::new(value_2)],
-            }],
-        });
+    pub fn having_between<V>(self, column: &str, min: V, max: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(
+            column,
+            Operator::Between,
+            Logic::And,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
+    /// Add an OR having between clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having_between("avg_duration", 300, 500);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration BETWEEN 300 AND 500"
+    /// );
+    /// ```
+    pub fn or_having_between<V>(self, column: &str, min: V, max: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(
+            column,
+            Operator::Between,
+            Logic::Or,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
+    /// Add a having IN clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_in("avg_duration", vec![60, 90]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration IN (60, 90)"
+    /// );
+    /// ```
+    pub fn having_in<V>(self, column: &str, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(column, Operator::In, Logic::And, boxed(values))
+    }
+
+    /// Add an OR having IN clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_gt("avg_duration", 300)
+    ///     .or_having_in("avg_duration", vec![60, 90]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration > 300 OR avg_duration IN (60, 90)"
+    /// );
+    /// ```
+    pub fn or_having_in<V>(self, column: &str, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(column, Operator::In, Logic::Or, boxed(values))
+    }
+
+    /// Add a having NULL clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_null("avg_duration");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration IS NULL"
+    /// );
+    /// ```
+    pub fn having_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNull, Logic::And, vec![])
+    }
+
+    /// Add an OR having NULL clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_gt("avg_duration", 300)
+    ///     .or_having_null("avg_duration");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration > 300 OR avg_duration IS NULL"
+    /// );
+    /// ```
+    pub fn or_having_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNull, Logic::Or, vec![])
+    }
+
+    /// Add a having NOT NULL clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_not_null("avg_duration");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration IS NOT NULL"
+    /// );
+    /// ```
+    pub fn having_not_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNotNull, Logic::And, vec![])
+    }
+
+    /// Add an OR having NOT NULL clause to the query.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_lt("avg_duration", 60)
+    ///     .or_having_not_null("avg_duration");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration < 60 OR avg_duration IS NOT NULL"
+    /// );
+    /// ```
+    pub fn or_having_not_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNotNull, Logic::Or, vec![])
+    }
+
+    /// Add a having closure clause to the query, the nested clauses are grouped in parentheses.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_not_null("avg_duration")
+    ///     .having_closure(|query| query.having_lt("avg_duration", 60).or_having_gt("avg_duration", 300));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration IS NOT NULL AND (avg_duration < 60 OR avg_duration > 300)"
+    /// );
+    /// ```
+    pub fn having_closure<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let nested_builder = closure(QueryBuilder::new());
+
+        self.having_closures
+            .push((Logic::And, nested_builder.havings));
+
+        self
+    }
+
+    /// Add an OR having closure clause to the query, the nested clauses are grouped in parentheses.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .select_avg("duration_in_min", "avg_duration")
+    ///     .group_by("origin")
+    ///     .having_gt("avg_duration", 300)
+    ///     .or_having_closure(|query| query.having_gte("avg_duration", 60).having_lte("avg_duration", 90));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin, AVG(duration_in_min) AS avg_duration FROM flights GROUP BY origin HAVING avg_duration > 300 OR (avg_duration >= 60 AND avg_duration <= 90)"
+    /// );
+    /// ```
+    pub fn or_having_closure<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let nested_builder = closure(QueryBuilder::new());
+
+        self.having_closures
+            .push((Logic::Or, nested_builder.havings));
+
+        self
+    }
+
+    /// Add a having clause that compares an aggregate function of a column, e.g. `AVG(duration_in_min) > 120`.
+    ///
+    /// The operator is one of `=`, `!=`, `<>`, `>`, `>=`, `<` or `<=`.
+    ///
+    /// ```
+    /// use eloquent_core::{Function, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .group_by("origin")
+    ///     .having_aggregate(Function::Avg, "duration_in_min", ">", 120);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights GROUP BY origin HAVING AVG(duration_in_min) > 120"
+    /// );
+    /// ```
+    pub fn having_aggregate(
+        self,
+        function: Function,
+        column: &str,
+        operator: &str,
+        value: impl ToSql + 'static,
+    ) -> Self {
+        self.add_aggregate_having(function, column, operator, Logic::And, value)
+    }
+
+    /// Add an OR having clause that compares an aggregate function of a column.
+    ///
+    /// ```
+    /// use eloquent_core::{Function, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .group_by("origin")
+    ///     .having_aggregate(Function::Min, "duration_in_min", "<", 60)
+    ///     .or_having_aggregate(Function::Max, "duration_in_min", ">", 600);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights GROUP BY origin HAVING MIN(duration_in_min) < 60 OR MAX(duration_in_min) > 600"
+    /// );
+    /// ```
+    pub fn or_having_aggregate(
+        self,
+        function: Function,
+        column: &str,
+        operator: &str,
+        value: impl ToSql + 'static,
+    ) -> Self {
+        self.add_aggregate_having(function, column, operator, Logic::Or, value)
+    }
+
+    /// Add a having clause on the `COUNT` of a column.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .group_by("origin")
+    ///     .having_count("id", ">", 5);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights GROUP BY origin HAVING COUNT(id) > 5"
+    /// );
+    /// ```
+    pub fn having_count(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Count, column, operator, value)
+    }
+
+    /// Add a having clause on the `SUM` of a column.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .group_by("origin")
+    ///     .having_sum("duration_in_min", ">=", 1000);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights GROUP BY origin HAVING SUM(duration_in_min) >= 1000"
+    /// );
+    /// ```
+    pub fn having_sum(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Sum, column, operator, value)
+    }
+
+    /// Add a having clause on the `AVG` of a column.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .group_by("origin")
+    ///     .having_avg("duration_in_min", "<", 120);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights GROUP BY origin HAVING AVG(duration_in_min) < 120"
+    /// );
+    /// ```
+    pub fn having_avg(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Avg, column, operator, value)
+    }
+
+    /// Add a having clause on the `MIN` of a column.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .group_by("origin")
+    ///     .having_min("duration_in_min", ">", 30);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights GROUP BY origin HAVING MIN(duration_in_min) > 30"
+    /// );
+    /// ```
+    pub fn having_min(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Min, column, operator, value)
+    }
+
+    /// Add a having clause on the `MAX` of a column.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .group_by("origin")
+    ///     .having_max("duration_in_min", "<=", 600);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT origin FROM flights GROUP BY origin HAVING MAX(duration_in_min) <= 600"
+    /// );
+    /// ```
+    pub fn having_max(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Max, column, operator, value)
+    }
+
+    fn add_aggregate_having(
+        mut self,
+        function: Function,
+        column: &str,
+        operator: &str,
+        logic: Logic,
+        value: impl ToSql + 'static,
+    ) -> Self {
+        let operator = Operator::from_comparison(operator)
+            .unwrap_or_else(|| Operator::Unsupported(operator.to_string()));
+
+        let mut condition = Condition::new(column, operator, logic, vec![Box::new(value)]);
+        condition.function = Some(function);
+
+        self.havings.push(condition);
 
         self
     }
 
-    fn add_having(mut self, column: &str, value: i64, operator: Operator) -> Self {
-        self.havings.push(Having {
-            conditions: vec![Condition {
-                field: column.to_string(),
-                operator,
-                logic: Logic::And,
-                values: vec![Box::new(value)],
-            }],
-        });
+    fn add_having(
+        mut self,
+        column: &str,
+        operator: Operator,
+        logic: Logic,
+        values: Vec<Box<dyn ToSql>>,
+    ) -> Self {
+        self.havings
+            .push(Condition::new(column, operator, logic, values));
 
         self
     }
 }
+
+fn boxed<V: ToSql + 'static>(values: Vec<V>) -> Vec<Box<dyn ToSql>> {
+    values
+        .into_iter()
+        .map(|value| Box::new(value) as Box<dyn ToSql>)
+        .collect()
+}
@@ -1,4 +1,4 @@
-use crate::{Insert, QueryBuilder, ToSql};
+use crate::{Columnable, Insert, InsertUsing, QueryBuilder, SubqueryBuilder, Table, ToSql};
 
 impl QueryBuilder {
     /// Insert single or multiple columns into the table.
@@ -56,6 +56,97 @@ impl QueryBuilder {
         self
     }
 
+    /// Insert the rows selected by a subquery into the given columns of the table.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("flights")
+    ///     .select(vec!["id", "flight_number"])
+    ///     .where_lt("departure_date", "2024-01-01");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("archived_flights")
+    ///     .insert_using(vec!["flight_id", "flight_number"], subquery);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO archived_flights (flight_id, flight_number) SELECT id, flight_number FROM flights WHERE departure_date < '2024-01-01'"
+    /// );
+    /// ```
+    pub fn insert_using<T>(mut self, columns: T, query: SubqueryBuilder) -> Self
+    where
+        T: Columnable,
+    {
+        self.insert_using = Some(InsertUsing {
+            columns: columns.to_columns(),
+            query,
+        });
+
+        self
+    }
+
+    /// Insert the columns of a struct that maps to a table, the table is taken from the struct
+    /// unless it has been set.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Table, ToSql};
+    ///
+    /// struct Flight {
+    ///     id: u64,
+    ///     flight_number: String,
+    ///     duration_in_min: u32,
+    /// }
+    ///
+    /// // usually derived with `#[derive(Table)]` from `eloquent_derive`
+    /// impl Table for Flight {
+    ///     fn table_name() -> &'static str {
+    ///         "flights"
+    ///     }
+    ///
+    ///     fn columns() -> Vec<&'static str> {
+    ///         vec!["id", "flight_number", "duration_in_min"]
+    ///     }
+    ///
+    ///     fn primary_key(&self) -> Vec<(&'static str, Box<dyn ToSql>)> {
+    ///         vec![("id", Box::new(self.id))]
+    ///     }
+    ///
+    ///     fn values(&self) -> Vec<(&'static str, Box<dyn ToSql>)> {
+    ///         vec![
+    ///             ("flight_number", Box::new(self.flight_number.clone())),
+    ///             ("duration_in_min", Box::new(self.duration_in_min)),
+    ///         ]
+    ///     }
+    /// }
+    ///
+    /// let flight = Flight {
+    ///     id: 1,
+    ///     flight_number: "KL123".to_string(),
+    ///     duration_in_min: 120,
+    /// };
+    ///
+    /// let result = QueryBuilder::new().insert_struct(&flight);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (id, flight_number, duration_in_min) VALUES (1, 'KL123', 120)"
+    /// );
+    /// ```
+    pub fn insert_struct<T>(mut self, row: &T) -> Self
+    where
+        T: Table,
+    {
+        if self.table.is_none() {
+            self.table = Some(T::table_name().to_string());
+        }
+
+        self.add_row(row.primary_key().into_iter().chain(row.values()).collect());
+
+        self
+    }
+
     fn add_insert(&mut self, column: &str, value: Box<dyn ToSql>) {
         if let Some(insert) = self.inserts.iter_mut().find(|i| i.column == column) {
             insert.values.push(value);
@@ -1,18 +1,45 @@
-use crate::{Join, JoinType, QueryBuilder};
+use crate::{Field, Join, JoinClause, JoinSource, JoinType, QueryBuilder, SubqueryBuilder};
 
 impl QueryBuilder {
     fn add_join(
-        mut self,
+        self,
         table: &str,
-        left_hand: &str,
-        right_hand: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
         join_type: JoinType,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            join_type,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    fn add_join_on<F>(self, table: &str, closure: F, join_type: JoinType) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            join_type,
+            closure(JoinClause::new()),
+        )
+    }
+
+    fn push_join(
+        mut self,
+        source: JoinSource,
+        alias: Option<&str>,
+        join_type: JoinType,
+        clause: JoinClause,
     ) -> Self {
         self.joins.push(Join {
-            table: table.to_string(),
-            left_hand: left_hand.to_string(),
+            source,
+            alias: alias.map(|alias| alias.to_string()),
             join_type,
-            right_hand: right_hand.to_string(),
+            clause,
         });
 
         self
@@ -36,7 +63,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights JOIN airports ON flights.origin_airport = airports.code"
     /// );
     /// ```
-    pub fn join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Inner)
     }
 
@@ -58,7 +85,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights LEFT JOIN airports ON flights.origin_airport = airports.code"
     /// );
     /// ```
-    pub fn left_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn left_join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Left)
     }
 
@@ -80,7 +107,7 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights RIGHT JOIN airports ON flights.origin_airport = airports.code"
     /// );
     /// ```
-    pub fn right_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn right_join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Right)
     }
 
@@ -102,7 +129,408 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights FULL JOIN airports ON flights.origin_airport = airports.code"
     /// );
     /// ```
-    pub fn full_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn full_join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Full)
     }
+
+    /// Inner join a table on one or more conditions built inside the closure.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .join_on("gates", |join| {
+    ///         join.on("gates.airport", "=", "flights.origin_airport")
+    ///             .and_on("gates.terminal", "=", "flights.terminal")
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights JOIN gates ON gates.airport = flights.origin_airport AND gates.terminal = flights.terminal"
+    /// );
+    /// ```
+    pub fn join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Inner)
+    }
+
+    /// Left join a table on one or more conditions built inside the closure.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .left_join_on("delays", |join| {
+    ///         join.on("delays.flight_id", "=", "flights.id")
+    ///             .on_value("delays.reason", "weather")
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights LEFT JOIN delays ON delays.flight_id = flights.id AND delays.reason = 'weather'"
+    /// );
+    /// ```
+    pub fn left_join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Left)
+    }
+
+    /// Right join a table on one or more conditions built inside the closure.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .right_join_on("airports", |join| {
+    ///         join.on("airports.code", "=", "flights.origin_airport")
+    ///             .or_on("airports.code", "=", "flights.destination_airport")
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights RIGHT JOIN airports ON airports.code = flights.origin_airport OR airports.code = flights.destination_airport"
+    /// );
+    /// ```
+    pub fn right_join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Right)
+    }
+
+    /// Full join a table on one or more conditions built inside the closure.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .full_join_on("seasons", |join| {
+    ///         join.on("flights.departure_date", ">=", "seasons.starts_at")
+    ///             .and_on("flights.departure_date", "<", "seasons.ends_at")
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights FULL JOIN seasons ON flights.departure_date >= seasons.starts_at AND flights.departure_date < seasons.ends_at"
+    /// );
+    /// ```
+    pub fn full_join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Full)
+    }
+
+    /// Inner join a table under an alias.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table_as("flights", "f")
+    ///     .join_as("airports", "a", "f.origin_airport", "a.code");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights AS f JOIN airports AS a ON f.origin_airport = a.code"
+    /// );
+    /// ```
+    pub fn join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Inner,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    /// Left join a table under an alias.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table_as("flights", "f")
+    ///     .left_join_as("airports", "a", "f.origin_airport", "a.code");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights AS f LEFT JOIN airports AS a ON f.origin_airport = a.code"
+    /// );
+    /// ```
+    pub fn left_join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Left,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    /// Right join a table under an alias.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table_as("flights", "f")
+    ///     .right_join_as("airports", "a", "f.origin_airport", "a.code");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights AS f RIGHT JOIN airports AS a ON f.origin_airport = a.code"
+    /// );
+    /// ```
+    pub fn right_join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Right,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    /// Full join a table under an alias.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table_as("flights", "f")
+    ///     .full_join_as("airports", "a", "f.origin_airport", "a.code");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights AS f FULL JOIN airports AS a ON f.origin_airport = a.code"
+    /// );
+    /// ```
+    pub fn full_join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Full,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    /// Inner join a subquery as a derived table under an alias.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .select("flight_id")
+    ///     .select_count("id", "booking_count")
+    ///     .group_by("flight_id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .join_sub(subquery, "b", "flights.id", "b.flight_id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights JOIN (SELECT flight_id, COUNT(id) AS booking_count FROM bookings GROUP BY flight_id) AS b ON flights.id = b.flight_id"
+    /// );
+    /// ```
+    pub fn join_sub(
+        self,
+        query: SubqueryBuilder,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::Inner,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    /// Left join a subquery as a derived table under an alias.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .select("flight_id")
+    ///     .select_count("id", "booking_count")
+    ///     .group_by("flight_id");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .left_join_sub(subquery, "b", "flights.id", "b.flight_id");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights LEFT JOIN (SELECT flight_id, COUNT(id) AS booking_count FROM bookings GROUP BY flight_id) AS b ON flights.id = b.flight_id"
+    /// );
+    /// ```
+    pub fn left_join_sub(
+        self,
+        query: SubqueryBuilder,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::Left,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    /// Cross join a table, combining every row with every row of the table.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .cross_join("seat_classes");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights CROSS JOIN seat_classes"
+    /// );
+    /// ```
+    pub fn cross_join(self, table: &str) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            JoinType::Cross,
+            JoinClause::new(),
+        )
+    }
+
+    /// Natural join a table on all columns with the same name.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .natural_join("flight_details");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights NATURAL JOIN flight_details"
+    /// );
+    /// ```
+    pub fn natural_join(self, table: &str) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            JoinType::Natural,
+            JoinClause::new(),
+        )
+    }
+
+    /// Join a subquery that can reference the columns of the preceding tables, rows without a match are dropped.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SqlServer, SubqueryBuilder};
+    ///
+    /// let latest_bookings = || {
+    ///     SubqueryBuilder::new()
+    ///         .table("bookings")
+    ///         .select("booked_at")
+    ///         .where_column("bookings.flight_id", "flights.id")
+    ///         .order_by_desc("booked_at")
+    ///         .limit(3)
+    /// };
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .join_lateral(latest_bookings(), "latest");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights CROSS JOIN LATERAL (SELECT booked_at FROM bookings WHERE bookings.flight_id = flights.id ORDER BY booked_at DESC LIMIT 3) AS latest"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(SqlServer)
+    ///     .table("flights")
+    ///     .join_lateral(latest_bookings(), "latest");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM [flights] CROSS APPLY (SELECT TOP 3 [booked_at] FROM [bookings] WHERE [bookings].[flight_id] = [flights].[id] ORDER BY [booked_at] DESC) AS [latest]"
+    /// );
+    /// ```
+    pub fn join_lateral(self, query: SubqueryBuilder, alias: &str) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::Lateral,
+            JoinClause::new(),
+        )
+    }
+
+    /// Join a subquery that can reference the columns of the preceding tables, rows without a match are kept.
+    ///
+    /// ```
+    /// use eloquent_core::{Postgres, QueryBuilder, SubqueryBuilder};
+    ///
+    /// let subquery = SubqueryBuilder::new()
+    ///     .table("bookings")
+    ///     .select("booked_at")
+    ///     .where_column("bookings.flight_id", "flights.id")
+    ///     .order_by_desc("booked_at")
+    ///     .limit(3);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Postgres)
+    ///     .table("flights")
+    ///     .left_join_lateral(subquery, "latest");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     r#"SELECT * FROM "flights" LEFT JOIN LATERAL (SELECT "booked_at" FROM "bookings" WHERE "bookings"."flight_id" = "flights"."id" ORDER BY "booked_at" DESC LIMIT 3) AS "latest" ON true"#
+    /// );
+    /// ```
+    pub fn left_join_lateral(self, query: SubqueryBuilder, alias: &str) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::LeftLateral,
+            JoinClause::new(),
+        )
+    }
 }
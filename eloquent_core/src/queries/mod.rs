@@ -1,4 +1,6 @@
+pub mod aggregates;
 pub mod conditions;
+pub mod ctes;
 pub mod deletes;
 pub mod group_by;
 pub mod havings;
@@ -8,6 +10,10 @@ pub mod limit;
 pub mod offset;
 pub mod order_by;
 pub mod paginate;
+pub mod returning;
 pub mod selects;
+pub mod set_operations;
 pub mod updates;
+pub mod upserts;
 pub mod when;
+pub mod windows;
@@ -1,4 +1,4 @@
-use crate::{Order, OrderColumn, QueryBuilder};
+use crate::{Order, OrderColumn, QueryBuilder, Selectable};
 
 impl QueryBuilder {
     /// Add an order by clause to the query in ascending order.
@@ -29,10 +29,34 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights ORDER BY origin ASC, destination DESC"
     /// );
     /// ```
-    pub fn order_by_asc(mut self, column: &str) -> Self {
+    ///
+    /// ```
+    /// use eloquent_core::{Case, QueryBuilder};
+    ///
+    /// let priority = Case::new()
+    ///     .when(|query| query.r#where("status", "boarding"), 1)
+    ///     .r#else(2);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .order_by_asc(priority)
+    ///     .order_by_asc("departure_time");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM flights ORDER BY CASE WHEN status = 'boarding' THEN 1 ELSE 2 END ASC, departure_time ASC"
+    /// );
+    /// ```
+    pub fn order_by_asc<T>(mut self, column: T) -> Self
+    where
+        T: Selectable,
+    {
+        let column_name = column.to_select_column();
+
         self.order_by.push(OrderColumn {
-            column: column.to_string(),
+            column: column_name,
             order: Order::Asc,
+            expression: column.to_select_expression(),
         });
 
         self
@@ -66,10 +90,16 @@ impl QueryBuilder {
     ///     "SELECT * FROM flights ORDER BY origin DESC, destination ASC"
     /// );
     /// ```
-    pub fn order_by_desc(mut self, column: &str) -> Self {
+    pub fn order_by_desc<T>(mut self, column: T) -> Self
+    where
+        T: Selectable,
+    {
+        let column_name = column.to_select_column();
+
         self.order_by.push(OrderColumn {
-            column: column.to_string(),
+            column: column_name,
             order: Order::Desc,
+            expression: column.to_select_expression(),
         });
 
         self
@@ -1,7 +1,7 @@
-use crate::{Paginate, QueryBuilder, ToSql};
+use crate::{CountQuery, Direction, Keyset, QueryBuilder, ToSql};
 
 impl QueryBuilder {
-    /// Paginate the query results.
+    /// Paginate the query results on a single column in ascending order, starting after the last id.
     ///
     /// ```
     /// use eloquent_core::QueryBuilder;
@@ -12,7 +12,7 @@ impl QueryBuilder {
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM departures LIMIT 25"
+    ///     "SELECT * FROM departures ORDER BY id ASC LIMIT 25"
     /// );
     /// ```
     ///
@@ -21,25 +21,117 @@ impl QueryBuilder {
     ///
     /// let result = QueryBuilder::new()
     ///     .table("departures")
+    ///     .r#where("status", "scheduled")
     ///     .paginate("id", Some(1000), 25);
     ///
     /// assert_eq!(
     ///     result.sql().unwrap(),
-    ///     "SELECT * FROM departures WHERE id > 1000 LIMIT 25"
+    ///     "SELECT * FROM departures WHERE status = 'scheduled' AND id > 1000 ORDER BY id ASC LIMIT 25"
     /// );
     /// ```
     pub fn paginate<T: ToSql + 'static>(
-        mut self,
+        self,
         column: &str,
         last_id: Option<T>,
         per_page: i64,
     ) -> Self {
-        self.paginate = Some(Paginate {
-            column: column.to_string(),
-            last_id: last_id.map(|id| Box::new(id) as Box<dyn ToSql>),
-            per_page: per_page as u64,
-        });
+        let mut keyset = Keyset::new(per_page as u64).order_by_asc(column);
+
+        if let Some(id) = last_id {
+            keyset = keyset.cursor_values(Direction::Next, vec![Box::new(id)]);
+        }
+
+        self.keyset(keyset)
+    }
+
+    /// Paginate the query results on a keyset of ordered columns, see [`Keyset`].
+    ///
+    /// The keyset columns are ordered before the columns of `order_by`, and a cursor to the
+    /// previous page selects the rows before it in reverse order.
+    ///
+    /// ```
+    /// use eloquent_core::{Cursor, Keyset, QueryBuilder, Value};
+    ///
+    /// let token = Cursor::previous(vec![Value::Int(1000)]).encode();
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .keyset(
+    ///         Keyset::new(25)
+    ///             .order_by_desc("id")
+    ///             .cursor(Cursor::decode(&token).unwrap()),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM departures WHERE id > 1000 ORDER BY id ASC LIMIT 25"
+    /// );
+    /// ```
+    pub fn keyset(mut self, keyset: Keyset) -> Self {
+        self.paginate = Some(keyset);
+
+        self
+    }
+
+    /// Select a page of the query results, pages start at 1.
+    ///
+    /// A page whose offset does not fit in a `u64` fails to compile with `PageOutOfRange`.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .order_by_asc("departure_time")
+    ///     .for_page(3, 25);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT * FROM departures ORDER BY departure_time ASC LIMIT 25 OFFSET 50"
+    /// );
+    /// ```
+    pub fn for_page(mut self, page: u64, per_page: u64) -> Self {
+        let offset = page.saturating_sub(1).checked_mul(per_page);
+
+        self.limit = Some(per_page);
+        self.offset = offset;
+        self.page_out_of_range = offset.is_none().then_some((page, per_page));
 
         self
     }
+
+    /// Count the rows of the query, e.g. the total of the pages selected with `for_page`.
+    ///
+    /// The order, limit and offset are left out, and a query with `GROUP BY`, `HAVING`, `DISTINCT`,
+    /// an aggregate select or a set operation is counted as a subquery.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let query = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .join("airports", "departures.airport_id", "airports.id")
+    ///     .r#where("airports.country", "NL")
+    ///     .order_by_asc("departure_time")
+    ///     .for_page(3, 25);
+    ///
+    /// assert_eq!(
+    ///     query.count_query().sql().unwrap(),
+    ///     "SELECT COUNT(*) FROM departures JOIN airports ON departures.airport_id = airports.id WHERE airports.country = 'NL'"
+    /// );
+    ///
+    /// let query = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .select("gate")
+    ///     .group_by("gate")
+    ///     .for_page(1, 10);
+    ///
+    /// assert_eq!(
+    ///     query.count_query().sql().unwrap(),
+    ///     "SELECT COUNT(*) FROM (SELECT gate FROM departures GROUP BY gate) AS aggregate_table"
+    /// );
+    /// ```
+    pub fn count_query(&self) -> CountQuery<'_> {
+        CountQuery::new(self)
+    }
 }
@@ -0,0 +1,39 @@
+use crate::{Columnable, QueryBuilder};
+
+impl QueryBuilder {
+    /// Return the given columns of the inserted, updated or deleted rows.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .insert("origin_airport", "AMS")
+    ///     .insert("destination_airport", "FRA")
+    ///     .returning(vec!["id", "created_at"]);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (origin_airport, destination_airport) VALUES ('AMS', 'FRA') RETURNING id, created_at"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .r#where("id", 1)
+    ///     .delete()
+    ///     .returning("flight_number");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "DELETE FROM flights WHERE id = 1 RETURNING flight_number"
+    /// );
+    /// ```
+    pub fn returning<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.returning.extend(columns.to_columns());
+
+        self
+    }
+}
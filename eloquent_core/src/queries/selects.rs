@@ -1,4 +1,4 @@
-use crate::{Columnable, Function, QueryBuilder, Select, Selectable, ToSql};
+use crate::{Columnable, Function, QueryBuilder, RawExpression, Select, Selectable, ToSql};
 
 impl QueryBuilder {
     /// Select single or multiple columns from the table.
@@ -56,6 +56,8 @@ impl QueryBuilder {
                 function: None,
                 column: column.to_string(),
                 alias: None,
+                expression: None,
+                over: None,
             });
         }
 
@@ -81,10 +83,14 @@ impl QueryBuilder {
     where
         T: Selectable,
     {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: None,
-            column: column.to_select_column(),
+            column: column_name,
             alias: Some(alias.to_string()),
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
@@ -121,15 +127,17 @@ impl QueryBuilder {
     /// );
     /// ```
     pub fn select_raw(mut self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
-        let mut formatted_raw = raw.to_string();
-        for value in values {
-            formatted_raw = formatted_raw.replacen('?', &value.to_sql().unwrap(), 1);
-        }
+        let values = values
+            .into_iter()
+            .map(|v| Box::new(v) as Box<dyn ToSql>)
+            .collect();
 
         self.selects.push(Select {
             function: None,
-            column: formatted_raw.to_string(),
+            column: raw.to_string(),
             alias: None,
+            expression: Some(Box::new(RawExpression::new(raw, values))),
+            over: None,
         });
 
         self
@@ -153,10 +161,14 @@ impl QueryBuilder {
     where
         T: Selectable,
     {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: Some(Function::Count),
-            column: column.to_select_column(),
+            column: column_name,
             alias: Some(alias.to_string()),
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
@@ -180,10 +192,14 @@ impl QueryBuilder {
     where
         T: Selectable,
     {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: Some(Function::Min),
-            column: column.to_select_column(),
+            column: column_name,
             alias: Some(alias.to_string()),
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
@@ -207,10 +223,14 @@ impl QueryBuilder {
     where
         T: Selectable,
     {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: Some(Function::Max),
-            column: column.to_select_column(),
+            column: column_name,
             alias: Some(alias.to_string()),
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
@@ -234,10 +254,14 @@ impl QueryBuilder {
     where
         T: Selectable,
     {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: Some(Function::Avg),
-            column: column.to_select_column(),
+            column: column_name,
             alias: Some(alias.to_string()),
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
@@ -261,10 +285,14 @@ impl QueryBuilder {
     where
         T: Selectable,
     {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: Some(Function::Sum),
-            column: column.to_select_column(),
+            column: column_name,
             alias: Some(alias.to_string()),
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
@@ -288,10 +316,14 @@ impl QueryBuilder {
     where
         T: Selectable,
     {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: Some(Function::Distinct),
-            column: column.to_select_column(),
+            column: column_name,
             alias: None,
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
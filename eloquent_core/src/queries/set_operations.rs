@@ -0,0 +1,162 @@
+use crate::{Combinable, QueryBuilder, SetOperation, SetOperator};
+
+impl QueryBuilder {
+    fn add_set_operation(
+        mut self,
+        operator: SetOperator,
+        query: impl Combinable + 'static,
+    ) -> Self {
+        self.set_operations.push(SetOperation {
+            operator,
+            query: Box::new(query),
+        });
+
+        self
+    }
+
+    /// Combine the results of the query with another query, removing duplicate rows.
+    ///
+    /// The order by, limit and offset clauses apply to the combined result, the combined query
+    /// cannot order, limit or have its own `WITH` clause.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let arrivals = SubqueryBuilder::new()
+    ///     .table("arrivals")
+    ///     .select("flight_number");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .select("flight_number")
+    ///     .union(arrivals)
+    ///     .order_by_asc("flight_number")
+    ///     .limit(10);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number FROM departures UNION SELECT flight_number FROM arrivals ORDER BY flight_number ASC LIMIT 10"
+    /// );
+    /// ```
+    pub fn union(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::Union, query)
+    }
+
+    /// Combine the results of the query with another query, keeping duplicate rows.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let arrivals = QueryBuilder::new()
+    ///     .table("arrivals")
+    ///     .select("flight_number")
+    ///     .r#where("terminal", 2);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .select("flight_number")
+    ///     .r#where("terminal", 1)
+    ///     .union_all(arrivals);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number FROM departures WHERE terminal = 1 UNION ALL SELECT flight_number FROM arrivals WHERE terminal = 2"
+    /// );
+    /// ```
+    pub fn union_all(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::UnionAll, query)
+    }
+
+    /// Keep only the rows that are also returned by another query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let arrivals = SubqueryBuilder::new()
+    ///     .table("arrivals")
+    ///     .select("flight_number");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .select("flight_number")
+    ///     .intersect(arrivals);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number FROM departures INTERSECT SELECT flight_number FROM arrivals"
+    /// );
+    /// ```
+    pub fn intersect(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::Intersect, query)
+    }
+
+    /// Remove the rows that are also returned by another query.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, SubqueryBuilder};
+    ///
+    /// let arrivals = SubqueryBuilder::new()
+    ///     .table("arrivals")
+    ///     .select("flight_number");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("departures")
+    ///     .select("flight_number")
+    ///     .except(arrivals);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number FROM departures EXCEPT SELECT flight_number FROM arrivals"
+    /// );
+    /// ```
+    pub fn except(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::Except, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Postgres, QueryBuilder, SqlServer, SubqueryBuilder, Value};
+
+    #[test]
+    fn test_set_operation_params_are_ordered() {
+        let (sql, params) = QueryBuilder::new()
+            .dialect(Postgres)
+            .table("departures")
+            .select("flight_number")
+            .r#where("terminal", 1)
+            .union(
+                SubqueryBuilder::new()
+                    .table("arrivals")
+                    .select("flight_number")
+                    .r#where("terminal", 2),
+            )
+            .to_sql_with_params()
+            .unwrap();
+
+        assert!(sql.ends_with("WHERE \"terminal\" = $2"));
+        assert_eq!(params, vec![Value::Int(1), Value::Int(2)]);
+    }
+
+    #[test]
+    fn test_set_operation_limit_on_sql_server() {
+        let sql = QueryBuilder::new()
+            .dialect(SqlServer)
+            .table("departures")
+            .select("flight_number")
+            .union(
+                SubqueryBuilder::new()
+                    .table("arrivals")
+                    .select("flight_number"),
+            )
+            .order_by_asc("flight_number")
+            .limit(10)
+            .sql()
+            .unwrap();
+
+        assert_eq!(
+            sql,
+            "SELECT [flight_number] FROM [departures] UNION SELECT [flight_number] FROM [arrivals] ORDER BY [flight_number] ASC OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY"
+        );
+    }
+}
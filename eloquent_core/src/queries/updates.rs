@@ -1,4 +1,4 @@
-use crate::{QueryBuilder, ToSql, Update};
+use crate::{Condition, Logic, Operator, QueryBuilder, Table, ToSql, Update};
 
 impl QueryBuilder {
     /// Update single or multiple columns in the table.
@@ -17,6 +17,24 @@ impl QueryBuilder {
     ///     "UPDATE flights SET origin_airport = 'AMS', destination_airport = 'FRA' WHERE id = 1"
     /// );
     /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Case, QueryBuilder};
+    ///
+    /// let status = Case::new()
+    ///     .when(|query| query.where_gt("delay_in_min", 15), "delayed")
+    ///     .r#else("on_time");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .update("status", status)
+    ///     .where_null("departed_at");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "UPDATE flights SET status = CASE WHEN delay_in_min > 15 THEN 'delayed' ELSE 'on_time' END WHERE departed_at IS NULL"
+    /// );
+    /// ```
     pub fn update(mut self, column: &str, value: impl ToSql + 'static) -> Self {
         self.updates.push(Update {
             column: column.to_string(),
@@ -25,4 +43,86 @@ impl QueryBuilder {
 
         self
     }
+
+    /// Update the columns of a struct that maps to a table, matching the row on its primary key.
+    /// The table is taken from the struct unless it has been set. A struct without a primary key
+    /// returns an error instead of updating every row.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Table, ToSql};
+    ///
+    /// struct Flight {
+    ///     id: u64,
+    ///     flight_number: String,
+    ///     duration_in_min: u32,
+    /// }
+    ///
+    /// // usually derived with `#[derive(Table)]` from `eloquent_derive`
+    /// impl Table for Flight {
+    ///     fn table_name() -> &'static str {
+    ///         "flights"
+    ///     }
+    ///
+    ///     fn columns() -> Vec<&'static str> {
+    ///         vec!["id", "flight_number", "duration_in_min"]
+    ///     }
+    ///
+    ///     fn primary_key(&self) -> Vec<(&'static str, Box<dyn ToSql>)> {
+    ///         vec![("id", Box::new(self.id))]
+    ///     }
+    ///
+    ///     fn values(&self) -> Vec<(&'static str, Box<dyn ToSql>)> {
+    ///         vec![
+    ///             ("flight_number", Box::new(self.flight_number.clone())),
+    ///             ("duration_in_min", Box::new(self.duration_in_min)),
+    ///         ]
+    ///     }
+    /// }
+    ///
+    /// let flight = Flight {
+    ///     id: 1,
+    ///     flight_number: "KL123".to_string(),
+    ///     duration_in_min: 120,
+    /// };
+    ///
+    /// let result = QueryBuilder::new().update_struct(&flight);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "UPDATE flights SET flight_number = 'KL123', duration_in_min = 120 WHERE id = 1"
+    /// );
+    /// ```
+    pub fn update_struct<T>(mut self, row: &T) -> Self
+    where
+        T: Table,
+    {
+        if self.table.is_none() {
+            self.table = Some(T::table_name().to_string());
+        }
+
+        for (column, value) in row.values() {
+            self.updates.push(Update {
+                column: column.to_string(),
+                value,
+            });
+        }
+
+        let primary_key = row.primary_key();
+
+        // without a primary key the update would rewrite every row of the table
+        if primary_key.is_empty() {
+            self.missing_primary_key = Some(T::table_name().to_string());
+        }
+
+        for (column, value) in primary_key {
+            self.conditions.push(Condition::new(
+                column,
+                Operator::Equal,
+                Logic::And,
+                vec![value],
+            ));
+        }
+
+        self
+    }
 }
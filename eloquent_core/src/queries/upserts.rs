@@ -0,0 +1,132 @@
+use crate::{Columnable, QueryBuilder, Upsert};
+
+impl QueryBuilder {
+    /// Resolve conflicts on the given columns of an insert, defaults to doing nothing.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("origin_airport", "AMS")
+    ///     .on_conflict("flight_number");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, origin_airport) VALUES ('KL123', 'AMS') ON CONFLICT (flight_number) DO NOTHING"
+    /// );
+    /// ```
+    pub fn on_conflict<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.upsert_mut().target = columns.to_columns();
+
+        self
+    }
+
+    /// Ignore the inserted row when it conflicts with an existing row.
+    ///
+    /// ```
+    /// use eloquent_core::{MySql, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(MySql)
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("origin_airport", "AMS")
+    ///     .on_conflict("flight_number")
+    ///     .do_nothing();
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO `flights` (`flight_number`, `origin_airport`) VALUES ('KL123', 'AMS') ON DUPLICATE KEY UPDATE `flight_number` = `flight_number`"
+    /// );
+    /// ```
+    pub fn do_nothing(mut self) -> Self {
+        self.upsert_mut().updates.clear();
+
+        self
+    }
+
+    /// Update the given columns of the conflicting row with the values of the inserted row.
+    ///
+    /// ```
+    /// use eloquent_core::{MySql, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("origin_airport", "AMS")
+    ///     .on_conflict("flight_number")
+    ///     .do_update("origin_airport");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, origin_airport) VALUES ('KL123', 'AMS') ON CONFLICT (flight_number) DO UPDATE SET origin_airport = EXCLUDED.origin_airport"
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(MySql)
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("origin_airport", "AMS")
+    ///     .on_conflict("flight_number")
+    ///     .do_update("origin_airport");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO `flights` (`flight_number`, `origin_airport`) VALUES ('KL123', 'AMS') ON DUPLICATE KEY UPDATE `origin_airport` = VALUES(`origin_airport`)"
+    /// );
+    /// ```
+    pub fn do_update<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.upsert_mut().updates.extend(columns.to_columns());
+
+        self
+    }
+
+    /// Only update the conflicting row when the conditions of the closure match.
+    ///
+    /// ```
+    /// use eloquent_core::QueryBuilder;
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .insert("flight_number", "KL123")
+    ///     .insert("status", "delayed")
+    ///     .on_conflict("flight_number")
+    ///     .do_update("status")
+    ///     .do_update_where(|query| query.where_not("flights.status", "cancelled"));
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "INSERT INTO flights (flight_number, status) VALUES ('KL123', 'delayed') ON CONFLICT (flight_number) DO UPDATE SET status = EXCLUDED.status WHERE flights.status != 'cancelled'"
+    /// );
+    /// ```
+    pub fn do_update_where<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let nested_builder = closure(QueryBuilder::new());
+
+        let upsert = self.upsert_mut();
+
+        upsert.conditions.extend(nested_builder.conditions);
+        upsert.closures.extend(nested_builder.closures);
+
+        self
+    }
+
+    fn upsert_mut(&mut self) -> &mut Upsert {
+        self.upsert.get_or_insert_with(|| Upsert {
+            target: Vec::new(),
+            updates: Vec::new(),
+            conditions: Vec::new(),
+            closures: Vec::new(),
+        })
+    }
+}
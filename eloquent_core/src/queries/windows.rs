@@ -0,0 +1,184 @@
+use crate::{Function, Over, QueryBuilder, Select, Window, WindowFunction};
+
+impl QueryBuilder {
+    /// Select the sequential number of each row within its window.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Window};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("flight_number")
+    ///     .select_row_number(Window::new().order_by_asc("departure_date"), "row_number");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number, ROW_NUMBER() OVER (ORDER BY departure_date ASC) AS row_number FROM flights"
+    /// );
+    /// ```
+    pub fn select_row_number(mut self, window: Window, alias: &str) -> Self {
+        self.add_window_select("", WindowFunction::RowNumber, window, alias);
+
+        self
+    }
+
+    /// Select the rank of each row within its window, with gaps after ties.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Window};
+    ///
+    /// let window = Window::new()
+    ///     .partition_by("origin_airport")
+    ///     .order_by_desc("flight_duration");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("flight_number")
+    ///     .select_rank(window, "duration_rank");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number, RANK() OVER (PARTITION BY origin_airport ORDER BY flight_duration DESC) AS duration_rank FROM flights"
+    /// );
+    /// ```
+    pub fn select_rank(mut self, window: Window, alias: &str) -> Self {
+        self.add_window_select("", WindowFunction::Rank, window, alias);
+
+        self
+    }
+
+    /// Select the rank of each row within its window, without gaps after ties.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Window};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("flight_number")
+    ///     .select_dense_rank(Window::new().order_by_desc("flight_duration"), "duration_rank");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number, DENSE_RANK() OVER (ORDER BY flight_duration DESC) AS duration_rank FROM flights"
+    /// );
+    /// ```
+    pub fn select_dense_rank(mut self, window: Window, alias: &str) -> Self {
+        self.add_window_select("", WindowFunction::DenseRank, window, alias);
+
+        self
+    }
+
+    /// Select the value of a column from the row `offset` rows before the current row.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Window};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("departure_date")
+    ///     .select_lag("price", 1, Window::new().order_by_asc("departure_date"), "previous_price");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT departure_date, LAG(price, 1) OVER (ORDER BY departure_date ASC) AS previous_price FROM flights"
+    /// );
+    /// ```
+    pub fn select_lag(mut self, column: &str, offset: u64, window: Window, alias: &str) -> Self {
+        self.add_window_select(column, WindowFunction::Lag(offset), window, alias);
+
+        self
+    }
+
+    /// Select the value of a column from the row `offset` rows after the current row.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Window};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("departure_date")
+    ///     .select_lead("price", 1, Window::new().order_by_asc("departure_date"), "next_price");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT departure_date, LEAD(price, 1) OVER (ORDER BY departure_date ASC) AS next_price FROM flights"
+    /// );
+    /// ```
+    pub fn select_lead(mut self, column: &str, offset: u64, window: Window, alias: &str) -> Self {
+        self.add_window_select(column, WindowFunction::Lead(offset), window, alias);
+
+        self
+    }
+
+    /// Select the value of a column from the first row of the window.
+    ///
+    /// ```
+    /// use eloquent_core::{QueryBuilder, Window};
+    ///
+    /// let window = Window::new()
+    ///     .partition_by("origin_airport")
+    ///     .order_by_asc("departure_date");
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("flight_number")
+    ///     .select_first_value("flight_number", window, "first_flight");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT flight_number, FIRST_VALUE(flight_number) OVER (PARTITION BY origin_airport ORDER BY departure_date ASC) AS first_flight FROM flights"
+    /// );
+    /// ```
+    pub fn select_first_value(mut self, column: &str, window: Window, alias: &str) -> Self {
+        self.add_window_select(column, WindowFunction::FirstValue, window, alias);
+
+        self
+    }
+
+    /// Select an aggregate function of a column over a window.
+    ///
+    /// ```
+    /// use eloquent_core::{FrameBound, Function, QueryBuilder, Window};
+    ///
+    /// let window = Window::new()
+    ///     .order_by_asc("departure_date")
+    ///     .rows_between(FrameBound::Preceding(6), FrameBound::CurrentRow);
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .select("departure_date")
+    ///     .select_over(Function::Avg, "price", window, "weekly_price");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "SELECT departure_date, AVG(price) OVER (ORDER BY departure_date ASC ROWS BETWEEN 6 PRECEDING AND CURRENT ROW) AS weekly_price FROM flights"
+    /// );
+    /// ```
+    pub fn select_over(
+        mut self,
+        function: Function,
+        column: &str,
+        window: Window,
+        alias: &str,
+    ) -> Self {
+        self.add_window_select(column, WindowFunction::Aggregate(function), window, alias);
+
+        self
+    }
+
+    fn add_window_select(
+        &mut self,
+        column: &str,
+        function: WindowFunction,
+        window: Window,
+        alias: &str,
+    ) {
+        self.selects.push(Select {
+            function: None,
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            expression: None,
+            over: Some(Over { function, window }),
+        });
+    }
+}
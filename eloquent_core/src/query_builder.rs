@@ -1,24 +1,41 @@
-use crate::{compiler::build_statement, error::EloquentError, Action, QueryBuilder};
+use crate::{
+    compiler::{build_statement, build_statement_with_params},
+    error::EloquentError,
+    Action, Dialect, Executor, Generic, QueryBuilder, Schema, Value,
+};
+use std::sync::Arc;
 
 impl QueryBuilder {
     /// Create a new instance of the QueryBuilder.
     pub fn new() -> Self {
         Self {
+            ctes: Vec::new(),
             table: None,
+            table_alias: None,
             selects: Vec::new(),
             inserts: Vec::new(),
+            insert_using: None,
+            upsert: None,
             updates: Vec::new(),
+            missing_primary_key: None,
             delete: false,
+            returning: Vec::new(),
             conditions: Vec::new(),
             closures: Vec::new(),
             joins: Vec::new(),
             havings: Vec::new(),
+            having_closures: Vec::new(),
             group_by: Vec::new(),
             order_by: Vec::new(),
             limit: None,
             offset: None,
+            page_out_of_range: None,
+            set_operations: Vec::new(),
             enable_checks: true,
             paginate: None,
+            aggregate: None,
+            dialect: Box::new(Generic),
+            schema: None,
         }
     }
 
@@ -29,6 +46,14 @@ impl QueryBuilder {
         self
     }
 
+    /// Set the table name for the query with an alias.
+    pub fn table_as(mut self, table: &str, alias: &str) -> Self {
+        self.table = Some(table.to_string());
+        self.table_alias = Some(alias.to_string());
+
+        self
+    }
+
     /// Skip the validation checks.
     pub fn skip_validation(mut self) -> Self {
         self.enable_checks = false;
@@ -36,11 +61,85 @@ impl QueryBuilder {
         self
     }
 
+    /// Set the SQL dialect the query is compiled to.
+    ///
+    /// ```
+    /// use eloquent_core::{Postgres, QueryBuilder};
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .dialect(Postgres)
+    ///     .table("flights")
+    ///     .select("origin")
+    ///     .where_year("departure_date", 2024);
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     r#"SELECT "origin" FROM "flights" WHERE EXTRACT(YEAR FROM "departure_date") = 2024"#
+    /// );
+    /// ```
+    pub fn dialect(mut self, dialect: impl Dialect + 'static) -> Self {
+        self.dialect = Box::new(dialect);
+
+        self
+    }
+
+    /// Validate the query against a schema instead of the globally registered one.
+    pub fn schema(mut self, schema: impl Into<Arc<Schema>>) -> Self {
+        self.schema = Some(schema.into());
+
+        self
+    }
+
     /// Compile the query to a SQL string.
     pub fn sql(self) -> Result<String, EloquentError> {
         build_statement(&self)
     }
 
+    /// Compile the query to a SQL string with positional placeholders and the ordered list of bound values.
+    ///
+    /// You can use the feature `bind-placeholder-questionmark` to control the use of `$` vs `?` for formatting.
+    ///
+    #[cfg_attr(
+        not(feature = "bind-placeholder-questionmark"),
+        doc = r##"```
+use eloquent_core::{QueryBuilder, Value};
+
+let (sql, params) = QueryBuilder::new()
+    .table("flights")
+    .r#where("origin_airport", "AMS")
+    .where_gt("flight_duration", 120)
+    .to_sql_with_params()
+    .unwrap();
+
+assert_eq!(
+    sql,
+    "SELECT * FROM flights WHERE origin_airport = $1 AND flight_duration > $2"
+);
+assert_eq!(
+    params,
+    vec![Value::Text("AMS".to_string()), Value::Int(120)]
+);
+```
+"##
+    )]
+    pub fn to_sql_with_params(&self) -> Result<(String, Vec<Value>), EloquentError> {
+        build_statement_with_params(self)
+    }
+
+    /// Execute the statement on an executor and return the number of affected rows.
+    pub fn execute(&self, executor: &mut impl Executor) -> Result<u64, EloquentError> {
+        let (sql, params) = self.to_sql_with_params()?;
+
+        executor.execute(&sql, &params)
+    }
+
+    /// Execute the query on an executor and return its rows.
+    pub fn fetch(&self, executor: &mut impl Executor) -> Result<Vec<Vec<Value>>, EloquentError> {
+        let (sql, params) = self.to_sql_with_params()?;
+
+        executor.query(&sql, &params)
+    }
+
     /// Compile the query to a formatted SQL string.
     pub fn pretty_sql(self) -> Result<String, EloquentError> {
         let unformatted_sql = build_statement(&self)?;
@@ -58,10 +157,14 @@ impl QueryBuilder {
         Ok(sql)
     }
 
+    pub(crate) fn active_schema(&self) -> Option<Arc<Schema>> {
+        self.schema.clone().or_else(Schema::global)
+    }
+
     pub(crate) fn get_action(&self) -> Action {
         if !self.selects.is_empty() {
             Action::Select
-        } else if !self.inserts.is_empty() {
+        } else if !self.inserts.is_empty() || self.insert_using.is_some() {
             Action::Insert
         } else if !self.updates.is_empty() {
             Action::Update
@@ -71,6 +174,13 @@ impl QueryBuilder {
             Action::Select
         }
     }
+
+    pub(crate) fn insert_columns(&self) -> Vec<&String> {
+        match &self.insert_using {
+            Some(insert_using) => insert_using.columns.iter().collect(),
+            None => self.inserts.iter().map(|insert| &insert.column).collect(),
+        }
+    }
 }
 
 impl Default for QueryBuilder {
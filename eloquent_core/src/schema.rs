@@ -0,0 +1,415 @@
+use crate::{error::EloquentError, JoinSource, JoinType, QueryBuilder, Value};
+use std::sync::{Arc, RwLock};
+
+static GLOBAL_SCHEMA: RwLock<Option<Arc<Schema>>> = RwLock::new(None);
+
+/// A catalogue of the tables and columns of a database, used to validate queries against.
+///
+/// A schema is registered on a builder with [`QueryBuilder::schema`] or for every builder with
+/// [`Schema::set_global`]. Queries are then checked for unknown tables and columns, `IS NULL`
+/// conditions on non-nullable columns and values that do not match the type of their column.
+///
+/// ```
+/// use eloquent_core::{error::EloquentError, ColumnType, QueryBuilder, Schema, TableSchema};
+///
+/// let schema = Schema::new().table(
+///     TableSchema::new("flights")
+///         .column("id", ColumnType::Integer)
+///         .column("flight_number", ColumnType::Text)
+///         .nullable_column("arrival_time", ColumnType::Timestamp),
+/// );
+///
+/// let result = QueryBuilder::new()
+///     .schema(schema)
+///     .table("flights")
+///     .insert("flight_number", 123)
+///     .sql();
+///
+/// assert_eq!(
+///     result,
+///     Err(EloquentError::IncompatibleColumnType(
+///         "flight_number".to_string(),
+///         "TEXT".to_string()
+///     ))
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(
+    any(feature = "schema-json", feature = "schema-toml"),
+    derive(serde::Deserialize)
+)]
+pub struct Schema {
+    #[cfg_attr(any(feature = "schema-json", feature = "schema-toml"), serde(default))]
+    tables: Vec<TableSchema>,
+}
+
+/// The columns of a single table in a [`Schema`].
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    any(feature = "schema-json", feature = "schema-toml"),
+    derive(serde::Deserialize)
+)]
+pub struct TableSchema {
+    name: String,
+    #[cfg_attr(any(feature = "schema-json", feature = "schema-toml"), serde(default))]
+    columns: Vec<ColumnSchema>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+    any(feature = "schema-json", feature = "schema-toml"),
+    derive(serde::Deserialize)
+)]
+pub(crate) struct ColumnSchema {
+    pub(crate) name: String,
+    #[cfg_attr(
+        any(feature = "schema-json", feature = "schema-toml"),
+        serde(rename = "type")
+    )]
+    pub(crate) column_type: ColumnType,
+    #[cfg_attr(any(feature = "schema-json", feature = "schema-toml"), serde(default))]
+    pub(crate) nullable: bool,
+}
+
+/// The type of the values stored in a column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+    any(feature = "schema-json", feature = "schema-toml"),
+    derive(serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
+pub enum ColumnType {
+    Integer,
+    #[cfg_attr(
+        any(feature = "schema-json", feature = "schema-toml"),
+        serde(rename = "bigint")
+    )]
+    BigInteger,
+    Float,
+    Text,
+    Varchar(u32),
+    Boolean,
+    Date,
+    Time,
+    Timestamp,
+}
+
+/// A column referenced by a query, resolved against the tables it selects from.
+pub(crate) enum ResolvedColumn<'a> {
+    Found(&'a ColumnSchema),
+    Unknown,
+    Unresolvable,
+}
+
+/// The tables of a query that are known to the schema, by name and alias, and whether they are
+/// on the nullable side of an outer join.
+pub(crate) struct SchemaScope<'a> {
+    tables: Vec<(String, &'a TableSchema, bool)>,
+    unresolvable_sources: bool,
+}
+
+impl Schema {
+    pub fn new() -> Self {
+        Self { tables: Vec::new() }
+    }
+
+    /// Add a table to the schema.
+    pub fn table(mut self, table: TableSchema) -> Self {
+        self.tables.push(table);
+
+        self
+    }
+
+    /// Register the schema for every builder that has no schema of its own.
+    ///
+    /// ```
+    /// use eloquent_core::{error::EloquentError, ColumnType, QueryBuilder, Schema, TableSchema};
+    ///
+    /// Schema::set_global(
+    ///     Schema::new().table(TableSchema::new("flights").column("id", ColumnType::Integer)),
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_gt("duration_in_min", 120)
+    ///     .sql();
+    ///
+    /// assert_eq!(
+    ///     result,
+    ///     Err(EloquentError::UnknownColumn("duration_in_min".to_string()))
+    /// );
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .schema(
+    ///         Schema::new().table(
+    ///             TableSchema::new("flights").column("duration_in_min", ColumnType::Integer),
+    ///         ),
+    ///     )
+    ///     .table("flights")
+    ///     .where_gt("duration_in_min", 120)
+    ///     .sql();
+    ///
+    /// assert_eq!(
+    ///     result.unwrap(),
+    ///     "SELECT * FROM flights WHERE duration_in_min > 120"
+    /// );
+    ///
+    /// Schema::clear_global();
+    ///
+    /// let result = QueryBuilder::new()
+    ///     .table("flights")
+    ///     .where_gt("duration_in_min", 120)
+    ///     .sql();
+    ///
+    /// assert!(result.is_ok());
+    /// ```
+    pub fn set_global(schema: impl Into<Arc<Schema>>) {
+        *GLOBAL_SCHEMA
+            .write()
+            .unwrap_or_else(|error| error.into_inner()) = Some(schema.into());
+    }
+
+    /// Remove the globally registered schema.
+    pub fn clear_global() {
+        *GLOBAL_SCHEMA
+            .write()
+            .unwrap_or_else(|error| error.into_inner()) = None;
+    }
+
+    pub(crate) fn global() -> Option<Arc<Schema>> {
+        GLOBAL_SCHEMA
+            .read()
+            .unwrap_or_else(|error| error.into_inner())
+            .clone()
+    }
+
+    /// Load a schema from a JSON description.
+    ///
+    /// ```
+    /// use eloquent_core::Schema;
+    ///
+    /// let schema = Schema::from_json(r#"{
+    ///     "tables": [{
+    ///         "name": "flights",
+    ///         "columns": [
+    ///             { "name": "id", "type": "integer" },
+    ///             { "name": "arrival_time", "type": "timestamp", "nullable": true }
+    ///         ]
+    ///     }]
+    /// }"#);
+    ///
+    /// assert!(schema.is_ok());
+    /// ```
+    #[cfg(feature = "schema-json")]
+    pub fn from_json(json: &str) -> Result<Self, EloquentError> {
+        serde_json::from_str(json).map_err(|error| EloquentError::InvalidSchema(error.to_string()))
+    }
+
+    /// Load a schema from a TOML description.
+    ///
+    /// ```
+    /// use eloquent_core::Schema;
+    ///
+    /// let schema = Schema::from_toml(r#"
+    ///     [[tables]]
+    ///     name = "flights"
+    ///     columns = [
+    ///         { name = "id", type = "integer" },
+    ///         { name = "arrival_time", type = "timestamp", nullable = true },
+    ///     ]
+    /// "#);
+    ///
+    /// assert!(schema.is_ok());
+    /// ```
+    #[cfg(feature = "schema-toml")]
+    pub fn from_toml(toml: &str) -> Result<Self, EloquentError> {
+        toml::from_str(toml).map_err(|error| EloquentError::InvalidSchema(error.to_string()))
+    }
+
+    pub(crate) fn find_table(&self, name: &str) -> Option<&TableSchema> {
+        self.tables.iter().find(|table| table.name == name)
+    }
+
+    /// The tables the query selects from, `CTE`s and subqueries cannot be resolved.
+    pub(crate) fn scope(&self, builder: &QueryBuilder) -> Result<SchemaScope<'_>, EloquentError> {
+        let mut scope = SchemaScope {
+            tables: Vec::new(),
+            unresolvable_sources: false,
+        };
+
+        // a RIGHT or FULL join makes every table before it nullable, a LEFT or FULL join the
+        // joined table itself
+        let outer_joined_before = |index: usize| {
+            builder.joins[index..]
+                .iter()
+                .any(|join| matches!(join.join_type, JoinType::Right | JoinType::Full))
+        };
+
+        let sources = builder
+            .table
+            .as_ref()
+            .map(|table| (table, builder.table_alias.as_ref(), outer_joined_before(0)))
+            .into_iter()
+            .chain(builder.joins.iter().enumerate().filter_map(
+                |(index, join)| match &join.source {
+                    JoinSource::Table(table) => Some((
+                        table,
+                        join.alias.as_ref(),
+                        matches!(join.join_type, JoinType::Left | JoinType::Full)
+                            || outer_joined_before(index + 1),
+                    )),
+                    JoinSource::Subquery(_) => None,
+                },
+            ));
+
+        for (name, alias, nullable) in sources {
+            if builder.ctes.iter().any(|cte| &cte.name == name) {
+                scope.unresolvable_sources = true;
+                continue;
+            }
+
+            let table = self
+                .find_table(name)
+                .ok_or_else(|| EloquentError::UnknownTable(name.to_string()))?;
+
+            scope.tables.push((name.to_string(), table, nullable));
+
+            if let Some(alias) = alias {
+                scope.tables.push((alias.to_string(), table, nullable));
+            }
+        }
+
+        scope.unresolvable_sources |= builder
+            .joins
+            .iter()
+            .any(|join| matches!(join.source, JoinSource::Subquery(_)));
+
+        Ok(scope)
+    }
+}
+
+impl TableSchema {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            columns: Vec::new(),
+        }
+    }
+
+    /// Add a non-nullable column to the table.
+    pub fn column(mut self, name: &str, column_type: ColumnType) -> Self {
+        self.columns.push(ColumnSchema {
+            name: name.to_string(),
+            column_type,
+            nullable: false,
+        });
+
+        self
+    }
+
+    /// Add a nullable column to the table.
+    pub fn nullable_column(mut self, name: &str, column_type: ColumnType) -> Self {
+        self.columns.push(ColumnSchema {
+            name: name.to_string(),
+            column_type,
+            nullable: true,
+        });
+
+        self
+    }
+
+    pub(crate) fn has_column(&self, name: &str) -> bool {
+        self.find_column(name).is_some()
+    }
+
+    fn find_column(&self, name: &str) -> Option<&ColumnSchema> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+}
+
+impl ColumnSchema {
+    /// Whether a bound value can be stored in the column.
+    pub(crate) fn accepts(&self, value: &Value) -> bool {
+        match value {
+            Value::Null => self.nullable,
+            Value::Int(_) | Value::UInt(_) => {
+                matches!(
+                    self.column_type,
+                    ColumnType::Integer | ColumnType::BigInteger | ColumnType::Float
+                )
+            }
+            Value::Float(_) => self.column_type == ColumnType::Float,
+            Value::Bool(_) => self.column_type == ColumnType::Boolean,
+            Value::Text(_) => matches!(
+                self.column_type,
+                ColumnType::Text
+                    | ColumnType::Varchar(_)
+                    | ColumnType::Date
+                    | ColumnType::Time
+                    | ColumnType::Timestamp
+            ),
+        }
+    }
+}
+
+impl SchemaScope<'_> {
+    /// Resolve a (possibly qualified) column, expressions are never resolved.
+    pub(crate) fn resolve(&self, column: &str) -> ResolvedColumn<'_> {
+        self.resolve_with_nullability(column).0
+    }
+
+    /// Resolve a column and whether it can be NULL because its table is outer joined.
+    pub(crate) fn resolve_with_nullability(&self, column: &str) -> (ResolvedColumn<'_>, bool) {
+        let is_identifier = column.split('.').all(|part| {
+            part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+
+        if !is_identifier {
+            return (ResolvedColumn::Unresolvable, false);
+        }
+
+        let (qualifier, name) = match column.rsplit_once('.') {
+            Some((qualifier, name)) => (Some(qualifier), name),
+            None => (None, column),
+        };
+
+        let mut tables = self
+            .tables
+            .iter()
+            .filter(|(table, _, _)| qualifier.is_none_or(|qualifier| qualifier == table))
+            .peekable();
+
+        if tables.peek().is_none() {
+            return (ResolvedColumn::Unresolvable, false);
+        }
+
+        if let Some((column, nullable)) = tables.find_map(|(_, table, nullable)| {
+            table.find_column(name).map(|column| (column, *nullable))
+        }) {
+            return (ResolvedColumn::Found(column), nullable);
+        }
+
+        if qualifier.is_none() && self.unresolvable_sources {
+            return (ResolvedColumn::Unresolvable, false);
+        }
+
+        (ResolvedColumn::Unknown, false)
+    }
+}
+
+impl std::fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnType::Integer => write!(f, "INTEGER"),
+            ColumnType::BigInteger => write!(f, "BIGINT"),
+            ColumnType::Float => write!(f, "FLOAT"),
+            ColumnType::Text => write!(f, "TEXT"),
+            ColumnType::Varchar(length) => write!(f, "VARCHAR({})", length),
+            ColumnType::Boolean => write!(f, "BOOLEAN"),
+            ColumnType::Date => write!(f, "DATE"),
+            ColumnType::Time => write!(f, "TIME"),
+            ColumnType::Timestamp => write!(f, "TIMESTAMP"),
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{
+    compilers::{indexes, tables},
+    error::EloquentError,
+    AlterTable, Dialect, Generic, IndexDefinition, Schema, TableDefinition,
+};
+use std::sync::Arc;
+
+/// The builder for statements that change the schema of a database, e.g. `CREATE TABLE`.
+///
+/// ```
+/// use eloquent_core::{ColumnDefinition, ColumnType, SchemaBuilder};
+///
+/// let result = SchemaBuilder::new().create_table("flights", |table| {
+///     table
+///         .if_not_exists()
+///         .column(ColumnDefinition::new("id", ColumnType::BigInteger).primary_key())
+///         .column(ColumnDefinition::new("flight_number", ColumnType::Varchar(10)).unique())
+///         .column(ColumnDefinition::new("status", ColumnType::Text).default("scheduled"))
+///         .column(ColumnDefinition::new("arrival_time", ColumnType::Timestamp).nullable())
+/// });
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "CREATE TABLE IF NOT EXISTS flights (id BIGINT NOT NULL PRIMARY KEY, flight_number VARCHAR(10) NOT NULL UNIQUE, status TEXT NOT NULL DEFAULT 'scheduled', arrival_time TIMESTAMP)"
+/// );
+/// ```
+pub struct SchemaBuilder {
+    pub(crate) statements: Vec<SchemaStatement>,
+    enable_checks: bool,
+    pub(crate) dialect: Box<dyn Dialect>,
+    schema: Option<Arc<Schema>>,
+}
+
+pub(crate) enum SchemaStatement {
+    Create(TableDefinition),
+    Alter(AlterTable),
+    Drop(String, bool),
+    CreateIndex(IndexDefinition),
+    DropIndex(String, String),
+}
+
+impl SchemaBuilder {
+    /// Create a new instance of the SchemaBuilder.
+    pub fn new() -> Self {
+        Self {
+            statements: Vec::new(),
+            enable_checks: true,
+            dialect: Box::new(Generic),
+            schema: None,
+        }
+    }
+
+    /// Create a table, the columns and constraints are defined inside the closure.
+    ///
+    /// ```
+    /// use eloquent_core::{ColumnDefinition, ColumnType, Postgres, SchemaBuilder};
+    ///
+    /// let result = SchemaBuilder::new()
+    ///     .dialect(Postgres)
+    ///     .create_table("bookings", |table| {
+    ///         table
+    ///             .column(ColumnDefinition::new("flight_id", ColumnType::Integer))
+    ///             .column(ColumnDefinition::new("seat", ColumnType::Varchar(4)))
+    ///             .column(ColumnDefinition::new("price", ColumnType::Float))
+    ///             .primary_key(vec!["flight_id", "seat"])
+    ///             .foreign_key("flight_id", "flights", "id")
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     r#"CREATE TABLE "bookings" ("flight_id" INTEGER NOT NULL, "seat" VARCHAR(4) NOT NULL, "price" DOUBLE PRECISION NOT NULL, PRIMARY KEY ("flight_id", "seat"), FOREIGN KEY ("flight_id") REFERENCES "flights" ("id"))"#
+    /// );
+    /// ```
+    pub fn create_table<F>(mut self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(TableDefinition) -> TableDefinition,
+    {
+        let definition = closure(TableDefinition::new(table));
+
+        self.statements.push(SchemaStatement::Create(definition));
+
+        self
+    }
+
+    /// Alter a table, every change is compiled to its own `ALTER TABLE` statement.
+    ///
+    /// ```
+    /// use eloquent_core::{ColumnDefinition, ColumnType, SchemaBuilder};
+    ///
+    /// let result = SchemaBuilder::new().alter_table("flights", |table| {
+    ///     table
+    ///         .add_column(ColumnDefinition::new("gate", ColumnType::Varchar(5)).nullable())
+    ///         .drop_column("terminal")
+    ///         .rename_column("duration", "duration_in_min")
+    /// });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "ALTER TABLE flights ADD COLUMN gate VARCHAR(5); ALTER TABLE flights DROP COLUMN terminal; ALTER TABLE flights RENAME COLUMN duration TO duration_in_min"
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// use eloquent_core::{Postgres, SchemaBuilder};
+    ///
+    /// let result = SchemaBuilder::new()
+    ///     .dialect(Postgres)
+    ///     .alter_table("flights", |table| {
+    ///         table
+    ///             .add_foreign_key("flights_origin_foreign", "origin", "airports", "code")
+    ///             .add_check("flights_duration_check", |query| query.where_gt("duration_in_min", 0))
+    ///             .drop_constraint("flights_number_unique")
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     r#"ALTER TABLE "flights" ADD CONSTRAINT "flights_origin_foreign" FOREIGN KEY ("origin") REFERENCES "airports" ("code"); ALTER TABLE "flights" ADD CONSTRAINT "flights_duration_check" CHECK ("duration_in_min" > 0); ALTER TABLE "flights" DROP CONSTRAINT "flights_number_unique""#
+    /// );
+    /// ```
+    pub fn alter_table<F>(mut self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(AlterTable) -> AlterTable,
+    {
+        let alter_table = closure(AlterTable::new(table));
+
+        self.statements.push(SchemaStatement::Alter(alter_table));
+
+        self
+    }
+
+    /// Drop a table.
+    ///
+    /// ```
+    /// use eloquent_core::SchemaBuilder;
+    ///
+    /// let result = SchemaBuilder::new().drop_table("flights");
+    ///
+    /// assert_eq!(result.sql().unwrap(), "DROP TABLE flights");
+    /// ```
+    pub fn drop_table(mut self, table: &str) -> Self {
+        self.statements
+            .push(SchemaStatement::Drop(table.to_string(), false));
+
+        self
+    }
+
+    /// Drop a table when it exists.
+    ///
+    /// ```
+    /// use eloquent_core::SchemaBuilder;
+    ///
+    /// let result = SchemaBuilder::new().drop_table_if_exists("flights");
+    ///
+    /// assert_eq!(result.sql().unwrap(), "DROP TABLE IF EXISTS flights");
+    /// ```
+    pub fn drop_table_if_exists(mut self, table: &str) -> Self {
+        self.statements
+            .push(SchemaStatement::Drop(table.to_string(), true));
+
+        self
+    }
+
+    /// Create an index on a table, the columns and options are defined inside the closure.
+    ///
+    /// The conditions of a partial index are built with the same methods as the conditions of a
+    /// query.
+    ///
+    /// ```
+    /// use eloquent_core::{Postgres, SchemaBuilder};
+    ///
+    /// let result = SchemaBuilder::new()
+    ///     .dialect(Postgres)
+    ///     .create_index("flights_number_unique", "flights", |index| {
+    ///         index
+    ///             .columns(vec!["flight_number", "departure_date"])
+    ///             .unique()
+    ///             .concurrently()
+    ///             .partial(|query| query.where_null("cancelled_at"))
+    ///     });
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     r#"CREATE UNIQUE INDEX CONCURRENTLY "flights_number_unique" ON "flights" ("flight_number", "departure_date") WHERE "cancelled_at" IS NULL"#
+    /// );
+    /// ```
+    pub fn create_index<F>(mut self, index: &str, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(IndexDefinition) -> IndexDefinition,
+    {
+        let definition = closure(IndexDefinition::new(index, table));
+
+        self.statements
+            .push(SchemaStatement::CreateIndex(definition));
+
+        self
+    }
+
+    /// Drop an index of a table.
+    ///
+    /// ```
+    /// use eloquent_core::{MySql, SchemaBuilder};
+    ///
+    /// let result = SchemaBuilder::new()
+    ///     .drop_index("flights_origin_index", "flights");
+    ///
+    /// assert_eq!(result.sql().unwrap(), "DROP INDEX flights_origin_index");
+    ///
+    /// let result = SchemaBuilder::new()
+    ///     .dialect(MySql)
+    ///     .drop_index("flights_origin_index", "flights");
+    ///
+    /// assert_eq!(
+    ///     result.sql().unwrap(),
+    ///     "DROP INDEX `flights_origin_index` ON `flights`"
+    /// );
+    /// ```
+    pub fn drop_index(mut self, index: &str, table: &str) -> Self {
+        self.statements.push(SchemaStatement::DropIndex(
+            index.to_string(),
+            table.to_string(),
+        ));
+
+        self
+    }
+
+    /// Skip the validation checks.
+    pub fn skip_validation(mut self) -> Self {
+        self.enable_checks = false;
+
+        self
+    }
+
+    /// Set the SQL dialect the statements are compiled to.
+    pub fn dialect(mut self, dialect: impl Dialect + 'static) -> Self {
+        self.dialect = Box::new(dialect);
+
+        self
+    }
+
+    /// Validate foreign keys against a schema instead of the globally registered one.
+    pub fn schema(mut self, schema: impl Into<Arc<Schema>>) -> Self {
+        self.schema = Some(schema.into());
+
+        self
+    }
+
+    /// Compile the statements to a single SQL string, separated by semicolons.
+    pub fn sql(self) -> Result<String, EloquentError> {
+        Ok(self.to_statements()?.join("; "))
+    }
+
+    /// Compile every statement to its own SQL string.
+    pub fn to_statements(&self) -> Result<Vec<String>, EloquentError> {
+        if self.statements.is_empty() {
+            return Err(EloquentError::MissingTable);
+        }
+
+        if self.enable_checks {
+            self.perform_checks()?;
+        }
+
+        let dialect = self.dialect.as_ref();
+        let mut statements = vec![];
+
+        for statement in &self.statements {
+            match statement {
+                SchemaStatement::Create(definition) => {
+                    statements.push(tables::format_create(definition, dialect)?);
+                }
+                SchemaStatement::Alter(alter_table) => {
+                    statements.extend(tables::format_alter(alter_table, dialect)?);
+                }
+                SchemaStatement::Drop(table, if_exists) => {
+                    statements.push(tables::format_drop(table, *if_exists, dialect));
+                }
+                SchemaStatement::CreateIndex(index) => {
+                    statements.push(indexes::format_create(index, dialect)?);
+                }
+                SchemaStatement::DropIndex(index, table) => {
+                    statements.push(indexes::format_drop(index, table, dialect));
+                }
+            }
+        }
+
+        for statement in &statements {
+            log::trace!(target: "eloquent", "{}", statement);
+        }
+
+        Ok(statements)
+    }
+
+    /// Whether the statements can run inside a transaction, e.g. not `CREATE INDEX CONCURRENTLY`.
+    #[cfg(feature = "migrations")]
+    pub(crate) fn is_transactional(&self) -> bool {
+        !self.statements.iter().any(|statement| {
+            matches!(statement, SchemaStatement::CreateIndex(index) if index.concurrently)
+        })
+    }
+
+    pub(crate) fn active_schema(&self) -> Option<Arc<Schema>> {
+        self.schema.clone().or_else(Schema::global)
+    }
+
+    /// The table with the given name that is created by one of the statements.
+    pub(crate) fn created_table(&self, name: &str) -> Option<&TableDefinition> {
+        self.statements
+            .iter()
+            .find_map(|statement| match statement {
+                SchemaStatement::Create(definition) if definition.name == name => Some(definition),
+                _ => None,
+            })
+    }
+}
+
+impl Default for SchemaBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
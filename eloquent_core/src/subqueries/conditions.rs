@@ -1,36 +1,72 @@
-use crate::{Columnable, Condition, Logic, Operator, QueryBuilder, SubqueryBuilder, ToSql};
+use crate::{
+    ColumnReference, Columnable, Comparable, Condition, Field, Logic, Operator, QueryBuilder,
+    SubqueryBuilder, ToSql,
+};
 
 impl SubqueryBuilder {
     fn add_condition(
         mut self,
-        field: &str,
+        field: impl Field,
         operator: Operator,
         logic: Logic,
         values: Vec<Box<dyn ToSql>>,
     ) -> Self {
         self.conditions
-            .push(Condition::new(field, operator, logic, values));
+            .push(Condition::new(&field.field_name(), operator, logic, values));
 
         self
     }
 
-    pub fn r#where(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn r#where<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Equal, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn or_where(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Equal, Logic::Or, vec![Box::new(value)])
     }
 
-    pub fn where_not(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_column(self, field: impl Field, column: impl Field) -> Self {
+        self.add_condition(
+            field.qualified_field_name(),
+            Operator::Equal,
+            Logic::And,
+            vec![Box::new(ColumnReference(column.qualified_field_name()))],
+        )
+    }
+
+    pub fn or_where_column(self, field: impl Field, column: impl Field) -> Self {
+        self.add_condition(
+            field.qualified_field_name(),
+            Operator::Equal,
+            Logic::Or,
+            vec![Box::new(ColumnReference(column.qualified_field_name()))],
+        )
+    }
+
+    pub fn where_not<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::NotEqual, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn or_where_not(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_not<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::NotEqual, Logic::Or, vec![Box::new(value)])
     }
 
-    pub fn where_gt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_gt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThan,
@@ -39,7 +75,10 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn or_where_gt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_gt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThan,
@@ -48,7 +87,10 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn where_gte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_gte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThanOrEqual,
@@ -57,7 +99,10 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn or_where_gte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_gte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::GreaterThanOrEqual,
@@ -66,15 +111,24 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn where_lt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_lt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::LessThan, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn or_where_lt(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_lt<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::LessThan, Logic::Or, vec![Box::new(value)])
     }
 
-    pub fn where_lte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_lte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::LessThanOrEqual,
@@ -83,7 +137,10 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn or_where_lte(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_lte<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::LessThanOrEqual,
@@ -92,12 +149,16 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn where_between(
+    pub fn where_between<V, W>(
         self,
-        field: &str,
-        min: impl ToSql + 'static,
-        max: impl ToSql + 'static,
-    ) -> Self {
+        field: impl Comparable<V> + Comparable<W>,
+        min: V,
+        max: W,
+    ) -> Self
+    where
+        V: ToSql + 'static,
+        W: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::Between,
@@ -106,12 +167,16 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn or_where_between(
+    pub fn or_where_between<V, W>(
         self,
-        field: &str,
-        min: impl ToSql + 'static,
-        max: impl ToSql + 'static,
-    ) -> Self {
+        field: impl Comparable<V> + Comparable<W>,
+        min: V,
+        max: W,
+    ) -> Self
+    where
+        V: ToSql + 'static,
+        W: ToSql + 'static,
+    {
         self.add_condition(
             field,
             Operator::Between,
@@ -120,15 +185,24 @@ impl SubqueryBuilder {
         )
     }
 
-    pub fn where_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_like<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Like, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn or_where_like(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn or_where_like<V>(self, field: impl Comparable<V>, value: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
         self.add_condition(field, Operator::Like, Logic::Or, vec![Box::new(value)])
     }
 
-    pub fn where_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn where_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -137,11 +211,14 @@ impl SubqueryBuilder {
         self.add_condition(field, Operator::In, Logic::And, boxed_values)
     }
 
-    pub fn where_in_subquery(self, field: &str, subquery: QueryBuilder) -> Self {
+    pub fn where_in_subquery(self, field: impl Field, subquery: QueryBuilder) -> Self {
         self.add_condition(field, Operator::In, Logic::And, vec![Box::new(subquery)])
     }
 
-    pub fn or_where_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn or_where_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -150,7 +227,10 @@ impl SubqueryBuilder {
         self.add_condition(field, Operator::In, Logic::Or, boxed_values)
     }
 
-    pub fn where_not_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn where_not_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -159,7 +239,10 @@ impl SubqueryBuilder {
         self.add_condition(field, Operator::NotIn, Logic::And, boxed_values)
     }
 
-    pub fn or_where_not_in(self, field: &str, values: Vec<impl ToSql + 'static>) -> Self {
+    pub fn or_where_not_in<V>(self, field: impl Comparable<V>, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
         let boxed_values = values
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn ToSql>)
@@ -182,7 +265,7 @@ impl SubqueryBuilder {
         self
     }
 
-    pub fn or_where_null(self, field: &str) -> Self {
+    pub fn or_where_null(self, field: impl Field) -> Self {
         self.add_condition(field, Operator::IsNull, Logic::Or, vec![])
     }
 
@@ -204,23 +287,44 @@ impl SubqueryBuilder {
         self
     }
 
-    pub fn or_where_not_null(self, field: &str) -> Self {
+    pub fn or_where_not_null(self, field: impl Field) -> Self {
         self.add_condition(field, Operator::IsNotNull, Logic::Or, vec![])
     }
 
-    pub fn where_date(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_date(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Date, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn where_year(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_year(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Year, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn where_month(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_month(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Month, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn where_day(self, field: &str, value: impl ToSql + 'static) -> Self {
+    pub fn where_day(self, field: impl Field, value: impl ToSql + 'static) -> Self {
         self.add_condition(field, Operator::Day, Logic::And, vec![Box::new(value)])
     }
+
+    pub fn where_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::Exists, Logic::And, vec![Box::new(subquery)])
+    }
+
+    pub fn or_where_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::Exists, Logic::Or, vec![Box::new(subquery)])
+    }
+
+    pub fn where_not_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition(
+            "",
+            Operator::NotExists,
+            Logic::And,
+            vec![Box::new(subquery)],
+        )
+    }
+
+    pub fn or_where_not_exists(self, subquery: SubqueryBuilder) -> Self {
+        self.add_condition("", Operator::NotExists, Logic::Or, vec![Box::new(subquery)])
+    }
 }
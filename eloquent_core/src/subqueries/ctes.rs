@@ -0,0 +1,34 @@
+use crate::{Columnable, Cte, SubqueryBuilder};
+
+impl SubqueryBuilder {
+    pub fn with(mut self, name: &str, query: SubqueryBuilder) -> Self {
+        self.ctes.push(Cte {
+            name: name.to_string(),
+            columns: Vec::new(),
+            query,
+            recursive: None,
+        });
+
+        self
+    }
+
+    pub fn with_recursive<T>(
+        mut self,
+        name: &str,
+        columns: T,
+        anchor: SubqueryBuilder,
+        recursive: SubqueryBuilder,
+    ) -> Self
+    where
+        T: Columnable,
+    {
+        self.ctes.push(Cte {
+            name: name.to_string(),
+            columns: columns.to_columns(),
+            query: anchor,
+            recursive: Some(recursive),
+        });
+
+        self
+    }
+}
@@ -1,53 +1,248 @@
-use crate::{Condition, Having, Logic, Operator, SubqueryBuilder};
+use crate::{Condition, Function, Logic, Operator, SubqueryBuilder, ToSql};
 
 impl SubqueryBuilder {
-    pub fn having(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::Equal)
+    pub fn having(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::Equal, Logic::And, vec![Box::new(value)])
     }
 
-    pub fn having_not(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::NotEqual)
+    pub fn having_not(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::NotEqual,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
-    pub fn having_gt(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::GreaterThan)
+    pub fn having_gt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThan,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
-    pub fn having_gte(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::GreaterThanOrEqual)
+    pub fn having_gte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThanOrEqual,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
-    pub fn having_lt(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::LessThan)
+    pub fn having_lt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::LessThan,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
-    pub fn having_lte(self, column: &str, value: i64) -> Self {
-        self.add_having(column, value, Operator::LessThanOrEqual)
+    pub fn having_lte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::LessThanOrEqual,
+            Logic::And,
+            vec![Box::new(value)],
+        )
     }
 
-    pub fn having_between(mut self, column: &str, value_1: i64, value_2: i64) -> Self {
-        self.havings.push(Having {
-            conditions: vec![Condition {
-                field: column.to_string(),
-                operator: Operator::Between,
-                logic: Logic::And,
-                values: vec![Box::new(value_1), Box::new(value_2)],
-            }],
-        });
+    pub fn or_having(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::Equal, Logic::Or, vec![Box::new(value)])
+    }
+
+    pub fn or_having_not(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::NotEqual, Logic::Or, vec![Box::new(value)])
+    }
+
+    pub fn or_having_gt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThan,
+            Logic::Or,
+            vec![Box::new(value)],
+        )
+    }
+
+    pub fn or_having_gte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::GreaterThanOrEqual,
+            Logic::Or,
+            vec![Box::new(value)],
+        )
+    }
+
+    pub fn or_having_lt(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(column, Operator::LessThan, Logic::Or, vec![Box::new(value)])
+    }
+
+    pub fn or_having_lte(self, column: &str, value: impl ToSql + 'static) -> Self {
+        self.add_having(
+            column,
+            Operator::LessThanOrEqual,
+            Logic::Or,
+            vec![Box::new(value)],
+        )
+    }
+
+    pub fn having_between<V>(self, column: &str, min: V, max: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(
+            column,
+            Operator::Between,
+            Logic::And,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
+    pub fn or_having_between<V>(self, column: &str, min: V, max: V) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(
+            column,
+            Operator::Between,
+            Logic::Or,
+            vec![Box::new(min), Box::new(max)],
+        )
+    }
+
+    pub fn having_in<V>(self, column: &str, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(column, Operator::In, Logic::And, boxed(values))
+    }
+
+    pub fn or_having_in<V>(self, column: &str, values: Vec<V>) -> Self
+    where
+        V: ToSql + 'static,
+    {
+        self.add_having(column, Operator::In, Logic::Or, boxed(values))
+    }
+
+    pub fn having_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNull, Logic::And, vec![])
+    }
+
+    pub fn or_having_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNull, Logic::Or, vec![])
+    }
+
+    pub fn having_not_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNotNull, Logic::And, vec![])
+    }
+
+    pub fn or_having_not_null(self, column: &str) -> Self {
+        self.add_having(column, Operator::IsNotNull, Logic::Or, vec![])
+    }
+
+    pub fn having_closure<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let nested_builder = closure(SubqueryBuilder::new());
+
+        self.having_closures
+            .push((Logic::And, nested_builder.havings));
+
+        self
+    }
+
+    pub fn or_having_closure<F>(mut self, closure: F) -> Self
+    where
+        F: FnOnce(Self) -> Self,
+    {
+        let nested_builder = closure(SubqueryBuilder::new());
+
+        self.having_closures
+            .push((Logic::Or, nested_builder.havings));
+
+        self
+    }
+
+    pub fn having_aggregate(
+        self,
+        function: Function,
+        column: &str,
+        operator: &str,
+        value: impl ToSql + 'static,
+    ) -> Self {
+        self.add_aggregate_having(function, column, operator, Logic::And, value)
+    }
+
+    pub fn or_having_aggregate(
+        self,
+        function: Function,
+        column: &str,
+        operator: &str,
+        value: impl ToSql + 'static,
+    ) -> Self {
+        self.add_aggregate_having(function, column, operator, Logic::Or, value)
+    }
+
+    pub fn having_count(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Count, column, operator, value)
+    }
+
+    pub fn having_sum(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Sum, column, operator, value)
+    }
+
+    pub fn having_avg(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Avg, column, operator, value)
+    }
+
+    pub fn having_min(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Min, column, operator, value)
+    }
+
+    pub fn having_max(self, column: &str, operator: &str, value: impl ToSql + 'static) -> Self {
+        self.having_aggregate(Function::Max, column, operator, value)
+    }
+
+    fn add_aggregate_having(
+        mut self,
+        function: Function,
+        column: &str,
+        operator: &str,
+        logic: Logic,
+        value: impl ToSql + 'static,
+    ) -> Self {
+        let operator = Operator::from_comparison(operator)
+            .unwrap_or_else(|| Operator::Unsupported(operator.to_string()));
+
+        let mut condition = Condition::new(column, operator, logic, vec![Box::new(value)]);
+        condition.function = Some(function);
+
+        self.havings.push(condition);
 
         self
     }
 
-    fn add_having(mut self, column: &str, value: i64, operator: Operator) -> Self {
-        self.havings.push(Having {
-            conditions: vec![Condition {
-                field: column.to_string(),
-                operator,
-                logic: Logic::And,
-                values: vec![Box::new(value)],
-            }],
-        });
+    fn add_having(
+        mut self,
+        column: &str,
+        operator: Operator,
+        logic: Logic,
+        values: Vec<Box<dyn ToSql>>,
+    ) -> Self {
+        self.havings
+            .push(Condition::new(column, operator, logic, values));
 
         self
     }
 }
+
+fn boxed<V: ToSql + 'static>(values: Vec<V>) -> Vec<Box<dyn ToSql>> {
+    values
+        .into_iter()
+        .map(|value| Box::new(value) as Box<dyn ToSql>)
+        .collect()
+}
@@ -1,36 +1,217 @@
-use crate::{Join, JoinType, SubqueryBuilder};
+use crate::{Field, Join, JoinClause, JoinSource, JoinType, SubqueryBuilder};
 
 impl SubqueryBuilder {
     fn add_join(
-        mut self,
+        self,
         table: &str,
-        left_hand: &str,
-        right_hand: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+        join_type: JoinType,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            join_type,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    fn add_join_on<F>(self, table: &str, closure: F, join_type: JoinType) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            join_type,
+            closure(JoinClause::new()),
+        )
+    }
+
+    fn push_join(
+        mut self,
+        source: JoinSource,
+        alias: Option<&str>,
         join_type: JoinType,
+        clause: JoinClause,
     ) -> Self {
         self.joins.push(Join {
-            table: table.to_string(),
-            left_hand: left_hand.to_string(),
+            source,
+            alias: alias.map(|alias| alias.to_string()),
             join_type,
-            right_hand: right_hand.to_string(),
+            clause,
         });
 
         self
     }
 
-    pub fn join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Inner)
     }
 
-    pub fn left_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn left_join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Left)
     }
 
-    pub fn right_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn right_join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Right)
     }
 
-    pub fn full_join(self, table: &str, left_hand: &str, right_hand: &str) -> Self {
+    pub fn full_join(self, table: &str, left_hand: impl Field, right_hand: impl Field) -> Self {
         self.add_join(table, left_hand, right_hand, JoinType::Full)
     }
+
+    pub fn join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Inner)
+    }
+
+    pub fn left_join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Left)
+    }
+
+    pub fn right_join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Right)
+    }
+
+    pub fn full_join_on<F>(self, table: &str, closure: F) -> Self
+    where
+        F: FnOnce(JoinClause) -> JoinClause,
+    {
+        self.add_join_on(table, closure, JoinType::Full)
+    }
+
+    pub fn join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Inner,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    pub fn left_join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Left,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    pub fn right_join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Right,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    pub fn full_join_as(
+        self,
+        table: &str,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            Some(alias),
+            JoinType::Full,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    pub fn join_sub(
+        self,
+        query: SubqueryBuilder,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::Inner,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    pub fn left_join_sub(
+        self,
+        query: SubqueryBuilder,
+        alias: &str,
+        left_hand: impl Field,
+        right_hand: impl Field,
+    ) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::Left,
+            JoinClause::columns(left_hand, right_hand),
+        )
+    }
+
+    pub fn cross_join(self, table: &str) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            JoinType::Cross,
+            JoinClause::new(),
+        )
+    }
+
+    pub fn natural_join(self, table: &str) -> Self {
+        self.push_join(
+            JoinSource::Table(table.to_string()),
+            None,
+            JoinType::Natural,
+            JoinClause::new(),
+        )
+    }
+
+    pub fn join_lateral(self, query: SubqueryBuilder, alias: &str) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::Lateral,
+            JoinClause::new(),
+        )
+    }
+
+    pub fn left_join_lateral(self, query: SubqueryBuilder, alias: &str) -> Self {
+        self.push_join(
+            JoinSource::Subquery(Box::new(query)),
+            Some(alias),
+            JoinType::LeftLateral,
+            JoinClause::new(),
+        )
+    }
 }
@@ -1,4 +1,5 @@
 pub mod conditions;
+pub mod ctes;
 pub mod group_by;
 pub mod havings;
 pub mod joins;
@@ -6,3 +7,5 @@ pub mod limit;
 pub mod offset;
 pub mod order_by;
 pub mod select;
+pub mod set_operations;
+pub mod windows;
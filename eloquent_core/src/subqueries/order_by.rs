@@ -1,19 +1,31 @@
-use crate::{Order, OrderColumn, SubqueryBuilder};
+use crate::{Order, OrderColumn, Selectable, SubqueryBuilder};
 
 impl SubqueryBuilder {
-    pub fn order_by_asc(mut self, column: &str) -> Self {
+    pub fn order_by_asc<T>(mut self, column: T) -> Self
+    where
+        T: Selectable,
+    {
+        let column_name = column.to_select_column();
+
         self.order_by.push(OrderColumn {
-            column: column.to_string(),
+            column: column_name,
             order: Order::Asc,
+            expression: column.to_select_expression(),
         });
 
         self
     }
 
-    pub fn order_by_desc(mut self, column: &str) -> Self {
+    pub fn order_by_desc<T>(mut self, column: T) -> Self
+    where
+        T: Selectable,
+    {
+        let column_name = column.to_select_column();
+
         self.order_by.push(OrderColumn {
-            column: column.to_string(),
+            column: column_name,
             order: Order::Desc,
+            expression: column.to_select_expression(),
         });
 
         self
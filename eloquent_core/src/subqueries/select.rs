@@ -1,4 +1,4 @@
-use crate::{Columnable, Function, Select, SubqueryBuilder, ToSql};
+use crate::{Columnable, Function, RawExpression, Select, Selectable, SubqueryBuilder, ToSql};
 
 impl SubqueryBuilder {
     pub fn select<T>(mut self, columns: T) -> Self
@@ -12,32 +12,43 @@ impl SubqueryBuilder {
                 function: None,
                 column: column.to_string(),
                 alias: None,
+                expression: None,
+                over: None,
             });
         }
 
         self
     }
 
-    pub fn select_as(mut self, column: &str, alias: &str) -> Self {
+    pub fn select_as<T>(mut self, column: T, alias: &str) -> Self
+    where
+        T: Selectable,
+    {
+        let column_name = column.to_select_column();
+
         self.selects.push(Select {
             function: None,
-            column: column.to_string(),
+            column: column_name,
             alias: Some(alias.to_string()),
+            expression: column.to_select_expression(),
+            over: None,
         });
 
         self
     }
 
     pub fn select_raw(mut self, raw: &str, values: Vec<impl ToSql + 'static>) -> Self {
-        let mut formatted_raw = raw.to_string();
-        for value in values {
-            formatted_raw = formatted_raw.replacen('?', &value.to_sql().unwrap(), 1);
-        }
+        let values = values
+            .into_iter()
+            .map(|v| Box::new(v) as Box<dyn ToSql>)
+            .collect();
 
         self.selects.push(Select {
             function: None,
-            column: formatted_raw.to_string(),
+            column: raw.to_string(),
             alias: None,
+            expression: Some(Box::new(RawExpression::new(raw, values))),
+            over: None,
         });
 
         self
@@ -48,6 +59,8 @@ impl SubqueryBuilder {
             function: Some(Function::Count),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            expression: None,
+            over: None,
         });
 
         self
@@ -58,6 +71,8 @@ impl SubqueryBuilder {
             function: Some(Function::Min),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            expression: None,
+            over: None,
         });
 
         self
@@ -68,6 +83,8 @@ impl SubqueryBuilder {
             function: Some(Function::Max),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            expression: None,
+            over: None,
         });
 
         self
@@ -78,6 +95,8 @@ impl SubqueryBuilder {
             function: Some(Function::Avg),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            expression: None,
+            over: None,
         });
 
         self
@@ -88,6 +107,8 @@ impl SubqueryBuilder {
             function: Some(Function::Sum),
             column: column.to_string(),
             alias: Some(alias.to_string()),
+            expression: None,
+            over: None,
         });
 
         self
@@ -98,6 +119,8 @@ impl SubqueryBuilder {
             function: Some(Function::Distinct),
             column: column.to_string(),
             alias: None,
+            expression: None,
+            over: None,
         });
 
         self
@@ -0,0 +1,32 @@
+use crate::{Combinable, SetOperation, SetOperator, SubqueryBuilder};
+
+impl SubqueryBuilder {
+    fn add_set_operation(
+        mut self,
+        operator: SetOperator,
+        query: impl Combinable + 'static,
+    ) -> Self {
+        self.set_operations.push(SetOperation {
+            operator,
+            query: Box::new(query),
+        });
+
+        self
+    }
+
+    pub fn union(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::Union, query)
+    }
+
+    pub fn union_all(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::UnionAll, query)
+    }
+
+    pub fn intersect(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::Intersect, query)
+    }
+
+    pub fn except(self, query: impl Combinable + 'static) -> Self {
+        self.add_set_operation(SetOperator::Except, query)
+    }
+}
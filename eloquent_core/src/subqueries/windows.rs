@@ -0,0 +1,67 @@
+use crate::{Function, Over, Select, SubqueryBuilder, Window, WindowFunction};
+
+impl SubqueryBuilder {
+    pub fn select_row_number(mut self, window: Window, alias: &str) -> Self {
+        self.add_window_select("", WindowFunction::RowNumber, window, alias);
+
+        self
+    }
+
+    pub fn select_rank(mut self, window: Window, alias: &str) -> Self {
+        self.add_window_select("", WindowFunction::Rank, window, alias);
+
+        self
+    }
+
+    pub fn select_dense_rank(mut self, window: Window, alias: &str) -> Self {
+        self.add_window_select("", WindowFunction::DenseRank, window, alias);
+
+        self
+    }
+
+    pub fn select_lag(mut self, column: &str, offset: u64, window: Window, alias: &str) -> Self {
+        self.add_window_select(column, WindowFunction::Lag(offset), window, alias);
+
+        self
+    }
+
+    pub fn select_lead(mut self, column: &str, offset: u64, window: Window, alias: &str) -> Self {
+        self.add_window_select(column, WindowFunction::Lead(offset), window, alias);
+
+        self
+    }
+
+    pub fn select_first_value(mut self, column: &str, window: Window, alias: &str) -> Self {
+        self.add_window_select(column, WindowFunction::FirstValue, window, alias);
+
+        self
+    }
+
+    pub fn select_over(
+        mut self,
+        function: Function,
+        column: &str,
+        window: Window,
+        alias: &str,
+    ) -> Self {
+        self.add_window_select(column, WindowFunction::Aggregate(function), window, alias);
+
+        self
+    }
+
+    fn add_window_select(
+        &mut self,
+        column: &str,
+        function: WindowFunction,
+        window: Window,
+        alias: &str,
+    ) {
+        self.selects.push(Select {
+            function: None,
+            column: column.to_string(),
+            alias: Some(alias.to_string()),
+            expression: None,
+            over: Some(Over { function, window }),
+        });
+    }
+}
@@ -1,18 +1,24 @@
-use crate::SubqueryBuilder;
+use crate::{
+    compiler::build_substatement_with_params, error::EloquentError, SubqueryBuilder, Value,
+};
 
 impl SubqueryBuilder {
     /// Create a new instance of the subquery builder.
     pub fn new() -> Self {
         Self {
+            ctes: Vec::new(),
             table: None,
+            table_alias: None,
             selects: Vec::new(),
             conditions: Vec::new(),
             joins: Vec::new(),
             havings: Vec::new(),
+            having_closures: Vec::new(),
             group_by: Vec::new(),
             order_by: Vec::new(),
             limit: None,
             offset: None,
+            set_operations: Vec::new(),
         }
     }
 
@@ -22,6 +28,19 @@ impl SubqueryBuilder {
 
         self
     }
+
+    /// Set the table name for the subquery with an alias.
+    pub fn table_as(mut self, table: &str, alias: &str) -> Self {
+        self.table = Some(table.to_string());
+        self.table_alias = Some(alias.to_string());
+
+        self
+    }
+
+    /// Compile the subquery to a SQL string with positional placeholders and the ordered list of bound values.
+    pub fn to_sql_with_params(&self) -> Result<(String, Vec<Value>), EloquentError> {
+        build_substatement_with_params(self)
+    }
 }
 
 impl Default for SubqueryBuilder {
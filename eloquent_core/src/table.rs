@@ -0,0 +1,21 @@
+use crate::ToSql;
+
+/// A struct that maps to a table, usually implemented with `#[derive(Table)]` from `eloquent_derive`.
+///
+/// The rows are used by [`QueryBuilder::insert_struct`](crate::QueryBuilder::insert_struct) and
+/// [`QueryBuilder::update_struct`](crate::QueryBuilder::update_struct).
+pub trait Table {
+    /// The name of the table.
+    fn table_name() -> &'static str;
+
+    /// The columns of the table, including the primary key.
+    fn columns() -> Vec<&'static str>;
+
+    /// The primary key columns with the values of this row.
+    fn primary_key(&self) -> Vec<(&'static str, Box<dyn ToSql>)> {
+        Vec::new()
+    }
+
+    /// The other columns with the values of this row.
+    fn values(&self) -> Vec<(&'static str, Box<dyn ToSql>)>;
+}
@@ -0,0 +1,276 @@
+use crate::{ColumnType, Columnable, Condition, Logic, QueryBuilder, ToSql};
+
+/// The columns and constraints of a `CREATE TABLE` statement, built inside the closure of
+/// [`SchemaBuilder::create_table`](crate::SchemaBuilder::create_table).
+pub struct TableDefinition {
+    pub(crate) name: String,
+    pub(crate) if_not_exists: bool,
+    pub(crate) columns: Vec<ColumnDefinition>,
+    pub(crate) primary_key: Vec<String>,
+    pub(crate) unique: Vec<Vec<String>>,
+    pub(crate) foreign_keys: Vec<ForeignKey>,
+}
+
+/// A column of a `CREATE TABLE` or `ALTER TABLE ... ADD COLUMN` statement.
+///
+/// Columns are `NOT NULL` unless they are marked as [`nullable`](ColumnDefinition::nullable).
+pub struct ColumnDefinition {
+    pub(crate) name: String,
+    pub(crate) column_type: ColumnType,
+    pub(crate) nullable: bool,
+    pub(crate) default: Option<Box<dyn ToSql>>,
+    pub(crate) primary_key: bool,
+    pub(crate) unique: bool,
+    pub(crate) references: Option<(String, String)>,
+}
+
+/// The changes of an `ALTER TABLE` statement, built inside the closure of
+/// [`SchemaBuilder::alter_table`](crate::SchemaBuilder::alter_table).
+pub struct AlterTable {
+    pub(crate) name: String,
+    pub(crate) operations: Vec<AlterOperation>,
+}
+
+pub(crate) struct ForeignKey {
+    pub(crate) columns: Vec<String>,
+    pub(crate) table: String,
+    pub(crate) references: Vec<String>,
+}
+
+pub(crate) enum AlterOperation {
+    Add(ColumnDefinition),
+    Drop(String),
+    Rename(String, String),
+    AddConstraint(String, TableConstraint),
+    DropConstraint(String),
+}
+
+pub(crate) enum TableConstraint {
+    PrimaryKey(Vec<String>),
+    Unique(Vec<String>),
+    ForeignKey(ForeignKey),
+    Check(Vec<Condition>, Vec<(Logic, Vec<Condition>)>),
+}
+
+impl TableDefinition {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            if_not_exists: false,
+            columns: Vec::new(),
+            primary_key: Vec::new(),
+            unique: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    /// Only create the table when it does not exist yet.
+    pub fn if_not_exists(mut self) -> Self {
+        self.if_not_exists = true;
+
+        self
+    }
+
+    /// Add a column to the table.
+    pub fn column(mut self, column: ColumnDefinition) -> Self {
+        self.columns.push(column);
+
+        self
+    }
+
+    /// Set a (composite) primary key on the table.
+    pub fn primary_key<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.primary_key = columns.to_columns();
+
+        self
+    }
+
+    /// Add a (composite) unique constraint to the table.
+    pub fn unique<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.unique.push(columns.to_columns());
+
+        self
+    }
+
+    /// Add a (composite) foreign key referencing the columns of another table.
+    pub fn foreign_key<T, U>(mut self, columns: T, table: &str, references: U) -> Self
+    where
+        T: Columnable,
+        U: Columnable,
+    {
+        self.foreign_keys.push(ForeignKey {
+            columns: columns.to_columns(),
+            table: table.to_string(),
+            references: references.to_columns(),
+        });
+
+        self
+    }
+
+    /// The columns and the tables and columns they reference.
+    pub(crate) fn references(&self) -> Vec<(&String, &String, &String)> {
+        let column_references = self.columns.iter().filter_map(|column| {
+            column
+                .references
+                .as_ref()
+                .map(|(table, reference)| (&column.name, table, reference))
+        });
+
+        let foreign_keys = self.foreign_keys.iter().flat_map(|foreign_key| {
+            foreign_key
+                .columns
+                .iter()
+                .zip(&foreign_key.references)
+                .map(|(column, reference)| (column, &foreign_key.table, reference))
+        });
+
+        column_references.chain(foreign_keys).collect()
+    }
+}
+
+impl ColumnDefinition {
+    pub fn new(name: &str, column_type: ColumnType) -> Self {
+        Self {
+            name: name.to_string(),
+            column_type,
+            nullable: false,
+            default: None,
+            primary_key: false,
+            unique: false,
+            references: None,
+        }
+    }
+
+    /// Allow the column to contain `NULL`.
+    pub fn nullable(mut self) -> Self {
+        self.nullable = true;
+
+        self
+    }
+
+    /// Set the default value of the column.
+    pub fn default(mut self, value: impl ToSql + 'static) -> Self {
+        self.default = Some(Box::new(value));
+
+        self
+    }
+
+    /// Make the column the primary key of the table.
+    pub fn primary_key(mut self) -> Self {
+        self.primary_key = true;
+
+        self
+    }
+
+    /// Add a unique constraint to the column.
+    pub fn unique(mut self) -> Self {
+        self.unique = true;
+
+        self
+    }
+
+    /// Reference a column of another table.
+    pub fn references(mut self, table: &str, column: &str) -> Self {
+        self.references = Some((table.to_string(), column.to_string()));
+
+        self
+    }
+}
+
+impl AlterTable {
+    pub(crate) fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            operations: Vec::new(),
+        }
+    }
+
+    /// Add a column to the table.
+    pub fn add_column(mut self, column: ColumnDefinition) -> Self {
+        self.operations.push(AlterOperation::Add(column));
+
+        self
+    }
+
+    /// Drop a column from the table.
+    pub fn drop_column(mut self, column: &str) -> Self {
+        self.operations
+            .push(AlterOperation::Drop(column.to_string()));
+
+        self
+    }
+
+    /// Rename a column of the table.
+    pub fn rename_column(mut self, from: &str, to: &str) -> Self {
+        self.operations
+            .push(AlterOperation::Rename(from.to_string(), to.to_string()));
+
+        self
+    }
+
+    /// Add a named (composite) primary key to the table.
+    pub fn add_primary_key<T>(self, name: &str, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.add_constraint(name, TableConstraint::PrimaryKey(columns.to_columns()))
+    }
+
+    /// Add a named (composite) unique constraint to the table.
+    pub fn add_unique<T>(self, name: &str, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.add_constraint(name, TableConstraint::Unique(columns.to_columns()))
+    }
+
+    /// Add a named (composite) foreign key referencing the columns of another table.
+    pub fn add_foreign_key<T, U>(self, name: &str, columns: T, table: &str, references: U) -> Self
+    where
+        T: Columnable,
+        U: Columnable,
+    {
+        self.add_constraint(
+            name,
+            TableConstraint::ForeignKey(ForeignKey {
+                columns: columns.to_columns(),
+                table: table.to_string(),
+                references: references.to_columns(),
+            }),
+        )
+    }
+
+    /// Add a named check constraint, the conditions are built inside the closure.
+    pub fn add_check<F>(self, name: &str, closure: F) -> Self
+    where
+        F: FnOnce(QueryBuilder) -> QueryBuilder,
+    {
+        let nested_builder = closure(QueryBuilder::new());
+
+        self.add_constraint(
+            name,
+            TableConstraint::Check(nested_builder.conditions, nested_builder.closures),
+        )
+    }
+
+    /// Drop a named constraint from the table.
+    pub fn drop_constraint(mut self, name: &str) -> Self {
+        self.operations
+            .push(AlterOperation::DropConstraint(name.to_string()));
+
+        self
+    }
+
+    fn add_constraint(mut self, name: &str, constraint: TableConstraint) -> Self {
+        self.operations
+            .push(AlterOperation::AddConstraint(name.to_string(), constraint));
+
+        self
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{EloquentError, ToSql};
+use crate::{Bindings, EloquentError, ToSql};
 
 #[derive(Debug)]
 pub struct Bind(u32);
@@ -9,6 +9,9 @@ pub struct Bind(u32);
 ///
 /// You can use the feature `bind-placeholder-questionmark` to control the use of `$` vs `?` for formatting.
 ///
+/// The placeholders of `to_sql_with_params` are numbered by the query, so a `bind` cannot be mixed
+/// with them and fails with `BindInParameterizedQuery`.
+///
 #[cfg_attr(
     not(feature = "bind-placeholder-questionmark"),
     doc = r##"/// ```
@@ -37,6 +40,14 @@ impl ToSql for Bind {
             Ok(format!("${}", self.0))
         }
     }
+
+    fn to_sql_bound(&self, bindings: &mut Bindings) -> Result<String, EloquentError> {
+        if !bindings.is_inline() {
+            return Err(EloquentError::BindInParameterizedQuery(self.0));
+        }
+
+        self.to_sql()
+    }
 }
 
 impl std::fmt::Display for Bind {
@@ -0,0 +1,95 @@
+use crate::{Dialect, EloquentError, Value};
+
+/// Collects the values bound while a statement is compiled for a dialect.
+///
+/// When inlining, values are rendered as SQL literals. Otherwise every value is replaced by a
+/// positional placeholder and pushed onto the ordered parameter list.
+pub struct Bindings<'a> {
+    dialect: &'a dyn Dialect,
+    values: Vec<Value>,
+    inline: bool,
+}
+
+impl<'a> Bindings<'a> {
+    pub(crate) fn inline(dialect: &'a dyn Dialect) -> Self {
+        Self {
+            dialect,
+            values: Vec::new(),
+            inline: true,
+        }
+    }
+
+    pub(crate) fn parameterized(dialect: &'a dyn Dialect) -> Self {
+        Self {
+            dialect,
+            values: Vec::new(),
+            inline: false,
+        }
+    }
+
+    /// Whether values are compiled as literals instead of being bound.
+    pub(crate) fn is_inline(&self) -> bool {
+        self.inline
+    }
+
+    /// The dialect the statement is compiled for.
+    pub fn dialect(&self) -> &'a dyn Dialect {
+        self.dialect
+    }
+
+    /// Bind a value and return the SQL that takes its place in the statement.
+    pub fn push(&mut self, value: Value) -> Result<String, EloquentError> {
+        if self.inline {
+            return Ok(self.literal(&value));
+        }
+
+        self.values.push(value);
+
+        Ok(self.dialect.placeholder(self.values.len()))
+    }
+
+    /// Quote a (possibly qualified) identifier, expressions are left untouched.
+    pub fn identifier(&self, identifier: &str) -> String {
+        let is_identifier = identifier.split('.').all(|part| {
+            part == "*"
+                || (part.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+                    && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'))
+        });
+
+        if !is_identifier {
+            return identifier.to_string();
+        }
+
+        identifier
+            .split('.')
+            .map(|part| match part {
+                "*" => part.to_string(),
+                _ => self.dialect.quote_identifier(part),
+            })
+            .collect::<Vec<String>>()
+            .join(".")
+    }
+
+    /// Quote a table name, followed by its alias when one is given.
+    pub(crate) fn table(&self, table: &str, alias: Option<&String>) -> String {
+        match alias {
+            Some(alias) => format!("{} AS {}", self.identifier(table), self.identifier(alias)),
+            None => self.identifier(table),
+        }
+    }
+
+    pub(crate) fn into_values(self) -> Vec<Value> {
+        self.values
+    }
+
+    fn literal(&self, value: &Value) -> String {
+        match value {
+            Value::Null => String::from("NULL"),
+            Value::Bool(value) => self.dialect.boolean_literal(*value),
+            Value::Int(value) => value.to_string(),
+            Value::UInt(value) => value.to_string(),
+            Value::Float(value) => value.to_string(),
+            Value::Text(value) => self.dialect.string_literal(value),
+        }
+    }
+}
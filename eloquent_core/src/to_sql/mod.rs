@@ -1,5 +1,9 @@
 mod bind;
+mod bindings;
+mod value;
 pub use bind::bind;
+pub use bindings::Bindings;
+pub use value::Value;
 
 #[cfg(feature = "enable-raw")]
 mod raw_sql;
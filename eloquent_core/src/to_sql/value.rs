@@ -0,0 +1,42 @@
+use crate::{EloquentError, ToSql};
+
+/// An owned, typed value that is bound to a positional placeholder.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Text(String),
+}
+
+impl ToSql for Value {
+    fn to_sql(&self) -> Result<String, EloquentError> {
+        match self {
+            Value::Null => Ok(String::from("NULL")),
+            Value::Bool(value) => value.to_sql(),
+            Value::Int(value) => value.to_sql(),
+            Value::UInt(value) => value.to_sql(),
+            Value::Float(value) => value.to_sql(),
+            Value::Text(value) => value.to_sql(),
+        }
+    }
+
+    fn to_value(&self) -> Option<Value> {
+        Some(self.clone())
+    }
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Null => write!(f, "NULL"),
+            Value::Bool(value) => write!(f, "{}", value),
+            Value::Int(value) => write!(f, "{}", value),
+            Value::UInt(value) => write!(f, "{}", value),
+            Value::Float(value) => write!(f, "{}", value),
+            Value::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{checks::*, error::EloquentError, PerformChecks, QueryBuilder};
+use crate::{checks::*, error::EloquentError, PerformChecks, QueryBuilder, SchemaBuilder};
 
 impl QueryBuilder {
     pub(crate) fn perform_checks(&self) -> Result<(), EloquentError> {
@@ -15,7 +15,30 @@ impl QueryBuilder {
         cannot_apply_clause_on_update::CannotApplyClauseOnUpdate::check(self)?;
         cannot_apply_clause_on_delete::CannotApplyClauseOnDelete::check(self)?;
         cannot_use_offset_limit_with_pagination::CannotUseOffsetLimitWithPagination::check(self)?;
+        inconsistent_keyset_cursor::InconsistentKeysetCursor::check(self)?;
         cannot_insert_with_different_columns::CannotInsertWithDifferentColumns::check(self)?;
+        duplicated_cte_names::DuplicatedCteNames::check(self)?;
+        cte_referenced_before_definition::CteReferencedBeforeDefinition::check(self)?;
+        cannot_combine_with_different_columns::CannotCombineWithDifferentColumns::check(self)?;
+        cannot_combine_non_select_or_ordered_queries::CannotCombineNonSelectOrOrderedQueries::check(self)?;
+        cannot_apply_clause_on_select::CannotApplyClauseOnSelect::check(self)?;
+        cannot_insert_using_different_columns::CannotInsertUsingDifferentColumns::check(self)?;
+        unknown_tables_and_columns::UnknownTablesAndColumns::check(self)?;
+        null_comparison_on_non_nullable_column::NullComparisonOnNonNullableColumn::check(self)?;
+        incompatible_column_types::IncompatibleColumnTypes::check(self)?;
+
+        Ok(())
+    }
+}
+
+impl SchemaBuilder {
+    pub(crate) fn perform_checks(&self) -> Result<(), EloquentError> {
+        missing_column_definitions::MissingColumnDefinitions::check(self)?;
+        duplicated_column_definitions::DuplicatedColumnDefinitions::check(self)?;
+        undeclared_key_columns::UndeclaredKeyColumns::check(self)?;
+        multiple_primary_keys::MultiplePrimaryKeys::check(self)?;
+        foreign_key_to_undeclared_column::ForeignKeyToUndeclaredColumn::check(self)?;
+        missing_index_columns::MissingIndexColumns::check(self)?;
 
         Ok(())
     }
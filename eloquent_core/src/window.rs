@@ -0,0 +1,211 @@
+use crate::{Bindings, Columnable, Function, Order, OrderColumn};
+use std::fmt::Display;
+
+/// The window of a window function, rendered as `OVER (...)`.
+///
+/// ```
+/// use eloquent_core::{FrameBound, QueryBuilder, Window};
+///
+/// let window = Window::new()
+///     .partition_by("origin_airport")
+///     .order_by_asc("departure_date")
+///     .rows_between(FrameBound::UnboundedPreceding, FrameBound::CurrentRow);
+///
+/// let result = QueryBuilder::new()
+///     .table("flights")
+///     .select("flight_number")
+///     .select_row_number(window, "row_number");
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "SELECT flight_number, ROW_NUMBER() OVER (PARTITION BY origin_airport ORDER BY departure_date ASC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS row_number FROM flights"
+/// );
+/// ```
+pub struct Window {
+    partition_by: Vec<String>,
+    order_by: Vec<OrderColumn>,
+    frame: Option<Frame>,
+}
+
+/// The start or end of a window frame.
+pub enum FrameBound {
+    UnboundedPreceding,
+    Preceding(u64),
+    CurrentRow,
+    Following(u64),
+    UnboundedFollowing,
+}
+
+struct Frame {
+    unit: FrameUnit,
+    start: FrameBound,
+    end: FrameBound,
+}
+
+enum FrameUnit {
+    Rows,
+    Range,
+}
+
+pub(crate) enum WindowFunction {
+    RowNumber,
+    Rank,
+    DenseRank,
+    Lag(u64),
+    Lead(u64),
+    FirstValue,
+    Aggregate(Function),
+}
+
+pub(crate) struct Over {
+    pub(crate) function: WindowFunction,
+    pub(crate) window: Window,
+}
+
+impl Window {
+    pub fn new() -> Self {
+        Self {
+            partition_by: Vec::new(),
+            order_by: Vec::new(),
+            frame: None,
+        }
+    }
+
+    /// Partition the rows of the window by one or more columns.
+    pub fn partition_by<T>(mut self, columns: T) -> Self
+    where
+        T: Columnable,
+    {
+        self.partition_by.extend(columns.to_columns());
+
+        self
+    }
+
+    /// Order the rows of the window in ascending order.
+    pub fn order_by_asc(mut self, column: &str) -> Self {
+        self.order_by.push(OrderColumn {
+            column: column.to_string(),
+            order: Order::Asc,
+            expression: None,
+        });
+
+        self
+    }
+
+    /// Order the rows of the window in descending order.
+    pub fn order_by_desc(mut self, column: &str) -> Self {
+        self.order_by.push(OrderColumn {
+            column: column.to_string(),
+            order: Order::Desc,
+            expression: None,
+        });
+
+        self
+    }
+
+    /// Limit the window to a frame of rows relative to the current row.
+    pub fn rows_between(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(Frame {
+            unit: FrameUnit::Rows,
+            start,
+            end,
+        });
+
+        self
+    }
+
+    /// Limit the window to a frame of values relative to the value of the current row.
+    pub fn range_between(mut self, start: FrameBound, end: FrameBound) -> Self {
+        self.frame = Some(Frame {
+            unit: FrameUnit::Range,
+            start,
+            end,
+        });
+
+        self
+    }
+
+    /// Whether the window partitions or orders by the given column.
+    pub(crate) fn references(&self, column: &str) -> bool {
+        self.partition_by
+            .iter()
+            .any(|partition| partition == column)
+            || self.order_by.iter().any(|order| order.column == column)
+    }
+
+    fn format(&self, bindings: &Bindings) -> String {
+        let mut clauses = vec![];
+
+        if !self.partition_by.is_empty() {
+            let columns = self
+                .partition_by
+                .iter()
+                .map(|column| bindings.identifier(column))
+                .collect::<Vec<String>>();
+
+            clauses.push(format!("PARTITION BY {}", columns.join(", ")));
+        }
+
+        if !self.order_by.is_empty() {
+            let columns = self
+                .order_by
+                .iter()
+                .map(|order| format!("{} {}", bindings.identifier(&order.column), order.order))
+                .collect::<Vec<String>>();
+
+            clauses.push(format!("ORDER BY {}", columns.join(", ")));
+        }
+
+        if let Some(frame) = &self.frame {
+            clauses.push(format!(
+                "{} BETWEEN {} AND {}",
+                frame.unit, frame.start, frame.end
+            ));
+        }
+
+        format!("OVER ({})", clauses.join(" "))
+    }
+}
+
+impl Default for Window {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Over {
+    pub(crate) fn format(&self, column: &str, bindings: &Bindings) -> String {
+        let function = match &self.function {
+            WindowFunction::RowNumber => "ROW_NUMBER()".to_string(),
+            WindowFunction::Rank => "RANK()".to_string(),
+            WindowFunction::DenseRank => "DENSE_RANK()".to_string(),
+            WindowFunction::Lag(offset) => format!("LAG({}, {})", column, offset),
+            WindowFunction::Lead(offset) => format!("LEAD({}, {})", column, offset),
+            WindowFunction::FirstValue => format!("FIRST_VALUE({})", column),
+            WindowFunction::Aggregate(function) => format!("{}({})", function, column),
+        };
+
+        format!("{} {}", function, self.window.format(bindings))
+    }
+}
+
+impl Display for FrameBound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameBound::UnboundedPreceding => write!(f, "UNBOUNDED PRECEDING"),
+            FrameBound::Preceding(rows) => write!(f, "{} PRECEDING", rows),
+            FrameBound::CurrentRow => write!(f, "CURRENT ROW"),
+            FrameBound::Following(rows) => write!(f, "{} FOLLOWING", rows),
+            FrameBound::UnboundedFollowing => write!(f, "UNBOUNDED FOLLOWING"),
+        }
+    }
+}
+
+impl Display for FrameUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameUnit::Rows => write!(f, "ROWS"),
+            FrameUnit::Range => write!(f, "RANGE"),
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! # Eloquent Derive
+//!
+//! Derive macros for [Eloquent](https://docs.rs/eloquent), enabled with the `derive` feature of the `eloquent` crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use proc_macro_crate::{crate_name, FoundCrate};
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident, LitStr, Type};
+
+/// Map a struct to a table.
+///
+/// Generates a `TABLE` constant with the table name, a typed `Column` constant for every field
+/// (e.g. `FLIGHT_NUMBER`) and an implementation of `Table`, so rows can be passed to
+/// `insert_struct` and `update_struct`.
+///
+/// The table name defaults to the struct name in snake case and can be set with
+/// `#[table(name = "...")]`. Fields accept `#[table(rename = "...")]`, `#[table(primary_key)]`
+/// and `#[table(skip)]`.
+///
+/// ```
+/// use eloquent_core::QueryBuilder;
+/// use eloquent_derive::Table;
+///
+/// #[derive(Table)]
+/// #[table(name = "flights")]
+/// struct Flight {
+///     #[table(primary_key)]
+///     id: u64,
+///     flight_number: String,
+///     #[table(rename = "duration_in_min")]
+///     duration: u32,
+/// }
+///
+/// let flight = Flight {
+///     id: 1,
+///     flight_number: "KL123".to_string(),
+///     duration: 120,
+/// };
+///
+/// let result = QueryBuilder::new()
+///     .table(Flight::TABLE)
+///     .select(Flight::FLIGHT_NUMBER)
+///     .where_gt(Flight::DURATION, 60);
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "SELECT flight_number FROM flights WHERE duration_in_min > 60"
+/// );
+///
+/// let result = QueryBuilder::new().update_struct(&flight);
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "UPDATE flights SET flight_number = 'KL123', duration_in_min = 120 WHERE id = 1"
+/// );
+/// ```
+///
+/// Skipped fields are left out of the columns and values, and without a primary key a row
+/// cannot be updated.
+///
+/// ```
+/// use eloquent_core::{error::EloquentError, QueryBuilder, Table};
+/// use eloquent_derive::Table;
+///
+/// #[derive(Table)]
+/// struct FlightDelay {
+///     flight_number: String,
+///     delay_in_min: u32,
+///     #[table(skip)]
+///     notified: bool,
+/// }
+///
+/// let delay = FlightDelay {
+///     flight_number: "KL123".to_string(),
+///     delay_in_min: 15,
+///     notified: false,
+/// };
+///
+/// assert_eq!(FlightDelay::TABLE, "flight_delay");
+/// assert_eq!(FlightDelay::columns(), vec!["flight_number", "delay_in_min"]);
+///
+/// let result = QueryBuilder::new().insert_struct(&delay);
+///
+/// assert_eq!(
+///     result.sql().unwrap(),
+///     "INSERT INTO flight_delay (flight_number, delay_in_min) VALUES ('KL123', 15)"
+/// );
+///
+/// let result = QueryBuilder::new().update_struct(&delay);
+///
+/// assert_eq!(
+///     result.sql(),
+///     Err(EloquentError::MissingPrimaryKey("flight_delay".to_string()))
+/// );
+/// ```
+///
+/// A field named `table` would collide with the `TABLE` constant and is rejected.
+///
+/// ```compile_fail
+/// use eloquent_derive::Table;
+///
+/// #[derive(Table)]
+/// struct Gate {
+///     table: String,
+/// }
+/// ```
+#[proc_macro_derive(Table, attributes(table))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_table(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct TableColumn {
+    field: Ident,
+    ty: Type,
+    name: String,
+    primary_key: bool,
+}
+
+fn expand_table(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let ident = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut table_name = to_snake_case(&ident.to_string());
+
+    for attr in &input.attrs {
+        if !attr.path().is_ident("table") {
+            continue;
+        }
+
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                table_name = meta.value()?.parse::<LitStr>()?.value();
+
+                return Ok(());
+            }
+
+            Err(meta.error("expected `name = \"...\"`"))
+        })?;
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    ident,
+                    "`Table` can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "`Table` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut columns = vec![];
+
+    for field in fields {
+        let field_ident = field.ident.clone().expect("named field");
+        let mut name = field_ident.to_string().trim_start_matches("r#").to_string();
+        let mut primary_key = false;
+        let mut skip = false;
+
+        for attr in &field.attrs {
+            if !attr.path().is_ident("table") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    name = meta.value()?.parse::<LitStr>()?.value();
+                } else if meta.path.is_ident("primary_key") {
+                    primary_key = true;
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                } else {
+                    return Err(meta.error("expected `rename = \"...\"`, `primary_key` or `skip`"));
+                }
+
+                Ok(())
+            })?;
+        }
+
+        if !skip && constant_name(&field_ident) == "TABLE" {
+            return Err(syn::Error::new_spanned(
+                &field_ident,
+                "a field named `table` collides with the generated `TABLE` constant, rename the field and keep the column with `#[table(rename = \"table\")]`",
+            ));
+        }
+
+        if !skip {
+            columns.push(TableColumn {
+                field: field_ident,
+                ty: field.ty.clone(),
+                name,
+                primary_key,
+            });
+        }
+    }
+
+    let eloquent = eloquent_path();
+
+    let constants = columns.iter().map(|column| {
+        let constant = Ident::new(&constant_name(&column.field), Span::call_site());
+        let ty = &column.ty;
+        let name = &column.name;
+
+        quote! {
+            pub const #constant: #eloquent::Column<#ty> = #eloquent::Column::new(#table_name, #name);
+        }
+    });
+
+    let names = columns.iter().map(|column| &column.name);
+
+    let primary_key = columns
+        .iter()
+        .filter(|column| column.primary_key)
+        .map(column_value);
+
+    let values = columns
+        .iter()
+        .filter(|column| !column.primary_key)
+        .map(column_value);
+
+    Ok(quote! {
+        impl #impl_generics #ident #type_generics #where_clause {
+            pub const TABLE: &'static str = #table_name;
+
+            #(#constants)*
+        }
+
+        impl #impl_generics #eloquent::Table for #ident #type_generics #where_clause {
+            fn table_name() -> &'static str {
+                #table_name
+            }
+
+            fn columns() -> ::std::vec::Vec<&'static str> {
+                ::std::vec![#(#names),*]
+            }
+
+            fn primary_key(&self) -> ::std::vec::Vec<(&'static str, ::std::boxed::Box<dyn #eloquent::ToSql>)> {
+                ::std::vec![#(#primary_key),*]
+            }
+
+            fn values(&self) -> ::std::vec::Vec<(&'static str, ::std::boxed::Box<dyn #eloquent::ToSql>)> {
+                ::std::vec![#(#values),*]
+            }
+        }
+    })
+}
+
+/// The name of the `Column` constant of a field, e.g. `FLIGHT_NUMBER`.
+fn constant_name(field: &Ident) -> String {
+    field.to_string().trim_start_matches("r#").to_uppercase()
+}
+
+fn column_value(column: &TableColumn) -> TokenStream2 {
+    let field = &column.field;
+    let name = &column.name;
+
+    quote! {
+        (#name, ::std::boxed::Box::new(::std::clone::Clone::clone(&self.#field)))
+    }
+}
+
+/// The path to the crate that exports `Table`, `eloquent` when used through the facade crate.
+fn eloquent_path() -> TokenStream2 {
+    for name in ["eloquent", "eloquent_core"] {
+        match crate_name(name) {
+            Ok(FoundCrate::Itself) => return quote!(crate),
+            Ok(FoundCrate::Name(name)) => {
+                let ident = Ident::new(&name, Span::call_site());
+
+                return quote!(::#ident);
+            }
+            Err(_) => continue,
+        }
+    }
+
+    quote!(::eloquent)
+}
+
+fn to_snake_case(name: &str) -> String {
+    let mut snake_case = String::new();
+
+    for (index, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if index > 0 {
+                snake_case.push('_');
+            }
+
+            snake_case.extend(c.to_lowercase());
+        } else {
+            snake_case.push(c);
+        }
+    }
+
+    snake_case
+}